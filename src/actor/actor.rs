@@ -184,6 +184,7 @@ pub type BoxActor<Msg> = Box<dyn Actor<Msg=Msg> + Send>;
 /// Supervision strategy
 /// 
 /// Returned in `Actor.supervision_strategy`
+#[derive(Clone, Debug, PartialEq)]
 pub enum Strategy {
     /// Stop the child actor
     Stop,
@@ -6,7 +6,8 @@ use std::{
     },
     time::{Duration, SystemTime},
     collections::HashMap,
-    ops::Deref
+    ops::Deref,
+    task::{self, Poll}
 };
 
 use chrono::prelude::*;
@@ -28,6 +29,7 @@ use crate::{
     },
     system::{
         ActorSystem, SystemMsg, SystemCmd, Run,
+        MailboxOverflow,
         timer::{Timer, Job, OnceJob, RepeatJob},
     },
     validate::InvalidPath
@@ -46,6 +48,7 @@ struct ActorCellInner {
     children: Children,
     is_remote: bool,
     is_terminating: Arc<AtomicBool>,
+    is_terminated: Arc<AtomicBool>,
     is_restarting: Arc<AtomicBool>,
     // persistence: Persistence,
     status: Arc<AtomicUsize>,
@@ -75,6 +78,7 @@ impl ActorCell {
                     children: Children::new(),
                     is_remote: false,
                     is_terminating: Arc::new(AtomicBool::new(false)),
+                    is_terminated: Arc::new(AtomicBool::new(false)),
                     is_restarting: Arc::new(AtomicBool::new(false)),
                     // persistence: Persistence {
                     //     // event_store: system.event_store.clone(),
@@ -133,24 +137,43 @@ impl ActorCell {
         self.inner.system.user_root().clone()
     }
 
+    pub(crate) fn system(&self) -> &ActorSystem {
+        &self.inner.system
+    }
+
     pub(crate) fn is_root(&self) -> bool {
         self.inner.uid == 0
     }
 
     pub fn is_user(&self) -> bool {
-        self.inner
-            .system
-            .user_root()
-            .is_child(&self.myself())
+        self.inner.uri.path.to_string().starts_with("/user/")
     }
 
     pub(crate) fn send_any_msg(&self, msg: &mut AnyMessage,
                                 sender: crate::actor::Sender)
-                                -> Result<(), ()> {
+                                -> Result<(), TellError> {
+        if self.is_terminated() {
+            return Err(TellError::Terminated);
+        }
+
         let mb = &self.inner.mailbox;
         let k = self.kernel();
         
         dispatch_any(msg, sender, mb, k, &self.inner.system)
+            .inspect_err(|e| self.publish_overflow(e))
+    }
+
+    /// Publishes a `MailboxOverflow` event if the mailbox of a user actor is full
+    ///
+    /// System actors are excluded, since an overflowing events channel
+    /// would otherwise publish to itself.
+    pub(crate) fn publish_overflow(&self, reason: &TellError) {
+        if let TellError::MailboxFull = reason {
+            if self.is_user() {
+                let overflow = MailboxOverflow { actor: self.myself() };
+                self.inner.system.publish_event(overflow.into());
+            }
+        }
     }
 
     pub(crate) fn send_sys_msg(&self, msg: Envelope<SystemMsg>) -> MsgResult<Envelope<SystemMsg>> {
@@ -164,6 +187,51 @@ impl ActorCell {
         self.inner.children.iter().any(|child| child == *actor)
     }
 
+    pub(crate) fn mailbox_len(&self) -> usize {
+        self.inner.mailbox.msg_count()
+    }
+
+    /// Returns the typed cell if the actor's mailbox accepts `Msg`
+    pub(crate) fn typed<Msg: Message>(&self) -> Option<ExtendedCell<Msg>> {
+        self.inner.mailbox
+            .as_any()
+            .downcast_ref::<MailboxSender<Msg>>()
+            .map(|mailbox| {
+                ExtendedCell {
+                    cell: self.clone(),
+                    mailbox: mailbox.clone()
+                }
+            })
+    }
+
+    pub(crate) fn is_scheduled(&self) -> bool {
+        self.inner.mailbox.is_sched()
+    }
+
+    /// Marks this incarnation of the actor as terminated.
+    ///
+    /// Messages sent using refs to a terminated actor are sent to
+    /// dead letters, even if a new actor has since been created at
+    /// the same path.
+    pub(crate) fn set_terminated(&self) {
+        self.inner.is_terminated.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_terminated(&self) -> bool {
+        self.inner.is_terminated.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn dead_letter<Msg: Message>(&self,
+                                            msg: Msg,
+                                            sender: crate::actor::Sender,
+                                            reason: DeadLetterReason) {
+        // dead letters of actors created while the system is starting are dropped
+        if let Some(chans) = self.inner.system.sys_channels.as_ref() {
+            let dl = DeadLetter::new(msg, reason, sender, self.myself());
+            chans.dead_letters.tell(Publish { topic: "dead_letter".into(), msg: dl }, None);
+        }
+    }
+
     pub(crate) fn stop(&self, actor: BasicActorRef) {
         actor.sys_tell(SystemCmd::Stop.into());
     }
@@ -362,6 +430,7 @@ impl<Msg> ExtendedCell<Msg>
                     children: Children::new(),
                     is_remote: false,
                     is_terminating: Arc::new(AtomicBool::new(false)),
+                    is_terminated: Arc::new(AtomicBool::new(false)),
                     is_restarting: Arc::new(AtomicBool::new(false)),
                     // persistence: Persistence {
                     //     // event_store: system.event_store.clone(),
@@ -390,7 +459,7 @@ impl<Msg> ExtendedCell<Msg>
     }
 
     pub fn myself(&self) -> ActorRef<Msg> {
-        self.cell.myself().typed(self.clone())
+        ActorRef::new(self.clone())
     }
 
     pub fn uri(&self) -> &ActorUri {
@@ -426,31 +495,42 @@ impl<Msg> ExtendedCell<Msg>
     }
 
     pub(crate) fn send_msg(&self, msg: Envelope<Msg>) -> MsgResult<Envelope<Msg>> {
-        let mb = &self.mailbox;
-        let k = self.cell.kernel();
-        
-        dispatch(msg, mb, k, &self.system())
-            .map_err(|e| {
-                let dl = e.clone(); // clone the failed message and send to dead letters
-                let dl = DeadLetter {
-                    msg: format!("{:?}", dl.msg.msg),
-                    sender: dl.msg.sender,
-                    recipient: self.cell.myself()
-                };
-
-                self.cell
-                    .inner.system
-                    .dead_letters()
-                    .tell(Publish { topic: "dead_letter".into(), msg: dl }, None);
-
-                e
-            })
+        self.try_send_msg(msg).map_err(|e| {
+            // clone the failed message and send to dead letters
+            let dl = e.clone();
+            self.cell.dead_letter(dl.msg.msg, dl.msg.sender, e.reason.into());
+
+            e
+        })
+    }
+
+    /// Returns `Ready` if the mailbox has space for a message
+    pub(crate) fn poll_mailbox_ready(&self, cx: &mut task::Context<'_>) -> Poll<()> {
+        self.mailbox.poll_ready(cx)
+    }
+
+    /// Sends the message without sending it to dead letters on failure
+    pub(crate) fn try_send_msg(&self, msg: Envelope<Msg>) -> MsgResult<Envelope<Msg>> {
+        if self.cell.is_terminated() {
+            return Err(MsgError::new(msg, TellError::Terminated));
+        }
+
+        dispatch(msg, &self.mailbox, self.cell.kernel(), &self.system())
+            .inspect_err(|e| self.cell.publish_overflow(&e.reason))
     }
 
     pub(crate) fn send_sys_msg(&self, msg: Envelope<SystemMsg>) -> MsgResult<Envelope<SystemMsg>> {
         self.cell.send_sys_msg(msg)
     }
 
+    pub(crate) fn mailbox_len(&self) -> usize {
+        self.mailbox.msg_count()
+    }
+
+    pub(crate) fn is_scheduled(&self) -> bool {
+        self.mailbox.is_sched()
+    }
+
     pub fn system(&self) -> &ActorSystem {
         &self.cell.inner.system
     }
@@ -543,18 +623,15 @@ impl<Msg> ActorSelectionFactory for Context<Msg>
     where Msg: Message
 {
     fn select(&self, path: &str) -> Result<ActorSelection, InvalidPath> {
-        let (anchor, path_str) = if path.starts_with("/") {
-            let anchor = self.system.user_root().clone();
-            let anchor_path = format!("{}/", anchor.path().deref().clone());
-            let path = path.to_string().replace(&anchor_path, "");
-
-            (anchor, path)
+        let (anchor, path_str) = if path.starts_with('/') {
+            // absolute paths are anchored at root, e.g. /user/a, /system/b
+            (self.system.root().clone(), path.trim_start_matches('/').to_string())
         } else {
             (self.myself.clone().into(), path.to_string())
         };
 
         ActorSelection::new(anchor,
-                            // self.system.dead_letters(),
+                            self.system.dead_letters(),
                             path_str)
     }
 }
@@ -674,11 +751,13 @@ impl Children {
             .insert(actor.name().to_string(), actor);
     }
 
+    /// Removes the child if it is the same incarnation as `actor`
     pub fn remove(&self, actor: &BasicActorRef) {
-        self.actors
-            .write()
-            .unwrap()
-            .remove(actor.name());
+        let mut actors = self.actors.write().unwrap();
+
+        if actors.get(actor.name()).map(|child| child == actor).unwrap_or(false) {
+            actors.remove(actor.name());
+        }
     }
 
     pub fn len(&self) -> usize {
@@ -1,11 +1,14 @@
-use std::fmt;
+use std::{
+    fmt,
+    sync::Arc
+};
 
 use crate::{
     Envelope, Message, AnyMessage,
     system::{ActorSystem, SystemMsg},
     actor::{
         Actor, ActorUri, ActorPath, BoxActorProd,
-        CreateError,
+        CreateError, TellError, SendError,
         actor_cell::{ActorCell, ExtendedCell}
     }
 };
@@ -119,7 +122,7 @@ impl<T> ActorReference for BoxedTell<T>
 
 impl<T> PartialEq for BoxedTell<T>  {
     fn eq(&self, other: &BoxedTell<T>) -> bool {
-        self.path() == other.path()
+        self.uri() == other.uri()
     }
 }
 
@@ -168,10 +171,12 @@ impl BasicActorRef {
         }
     }
 
-    pub fn typed<Msg: Message>(&self, cell: ExtendedCell<Msg>) -> ActorRef<Msg> {
-        ActorRef {
-            cell
-        }
+    /// Returns a typed `ActorRef` if the actor's message type is `Msg`
+    /// 
+    /// The message type is checked at runtime against the actor's
+    /// mailbox. If it doesn't match `None` is returned.
+    pub fn typed<Msg: Message>(&self) -> Option<ActorRef<Msg>> {
+        self.cell.typed().map(ActorRef::new)
     }
 
     pub(crate) fn sys_init(&self, sys: &ActorSystem) {
@@ -180,20 +185,58 @@ impl BasicActorRef {
 
     /// Send a message to this actor
     /// 
-    /// Returns a result. If the message type is not supported Error is returned.
+    /// Returns a result. If the message type is not supported, the actor
+    /// has terminated or its mailbox is full, a `TellError` is returned
+    /// and the message is sent to dead letters.
     pub fn try_tell<Msg>(&self, msg: Msg,
                             sender: impl Into<Option<BasicActorRef>>)
-                            -> Result<(), ()>
+                            -> Result<(), TellError>
         where Msg: Message + Send
     {
-        self.try_tell_any(&mut AnyMessage::new(msg, true), sender)
+        self.try_tell_dl(msg, sender.into(), true)
+    }
+
+    /// Like `try_tell`, but messages of a type not supported by the
+    /// actor are dropped instead of being sent to dead letters
+    /// 
+    /// Used when sending to many actors, such as a selection or group
+    /// routees, where not every actor is expected to accept the message.
+    pub(crate) fn try_tell_supported<Msg>(&self, msg: Msg, sender: Sender)
+                                        -> Result<(), TellError>
+        where Msg: Message + Send
+    {
+        self.try_tell_dl(msg, sender, false)
+    }
+
+    fn try_tell_dl<Msg>(&self, msg: Msg, sender: Sender, dl_unsupported: bool)
+                        -> Result<(), TellError>
+        where Msg: Message + Send
+    {
+        let mut msg = AnyMessage::new(msg, true);
+
+        self.try_tell_any(&mut msg, sender.clone())
+            .inspect_err(|&e| {
+                if e == TellError::UnsupportedType && !dl_unsupported {
+                    return;
+                }
+
+                // an undelivered message is left in `msg`
+                if let Ok(msg) = msg.take::<Msg>() {
+                    self.cell.dead_letter(msg, sender, e.into());
+                }
+            })
     }
 
     pub fn try_tell_any(&self, msg: &mut AnyMessage,
                         sender: impl Into<Option<BasicActorRef>>)
-                        -> Result<(), ()> {
+                        -> Result<(), TellError> {
         self.cell.send_any_msg(msg, sender.into())
     }
+
+    /// Number of messages waiting in this actor's mailbox
+    pub fn mailbox_len(&self) -> usize {
+        self.cell.mailbox_len()
+    }
 }
 
 impl ActorReference for BasicActorRef {
@@ -302,7 +345,7 @@ impl fmt::Display for BasicActorRef {
 
 impl PartialEq for BasicActorRef {
     fn eq(&self, other: &BasicActorRef) -> bool {
-        self.cell.uri().path == other.cell.uri().path
+        self.cell.uri() == other.cell.uri()
     }
 }
 
@@ -365,6 +408,52 @@ impl<Msg: Message> ActorRef<Msg> {
         // consume the result (we don't return it to user)
         let _ = self.cell.send_msg(envelope);
     }
+
+    /// Send a message to this actor, returning it if it can't be sent
+    /// 
+    /// Unlike `tell`, messages that can't be sent are not sent to
+    /// dead letters. Instead the message is returned with the reason
+    /// so the caller can decide what to do with it.
+    pub fn try_send(&self,
+                    msg: Msg,
+                    sender: impl Into<Option<BasicActorRef>>)
+                    -> Result<(), SendError<Msg>> {
+        let envelope = Envelope {
+            msg,
+            sender: sender.into(),
+        };
+
+        self.cell.try_send_msg(envelope)
+            .map_err(|e| SendError { msg: e.msg.msg, reason: e.reason })
+    }
+
+    /// Number of messages waiting in this actor's mailbox
+    pub fn mailbox_len(&self) -> usize {
+        self.cell.mailbox_len()
+    }
+
+    /// Returns a reference that accepts `T` and converts it to `Msg` on send
+    /// 
+    /// The conversion happens on the sending thread so no extra actor
+    /// is created. The returned `BoxedTell<T>` can be used anywhere
+    /// a `BoxedTell` is accepted, such as channel subscriptions, and as
+    /// a reply-to field in messages to actors that reply with `T`.
+    /// 
+    /// Since no actor is created, an adapted reference can't be used as
+    /// a message's `Sender` or passed to `Timer`, which takes an
+    /// `ActorRef`. Timers convert messages using `Into`, so if
+    /// `T: Into<Msg>` schedule `T` to this actor directly instead.
+    /// 
+    /// The adapted reference refers to the same actor, so it compares
+    /// equal to other references to this actor.
+    pub fn adapt<T, F>(&self, f: F) -> BoxedTell<T>
+        where T: Message, F: Fn(T) -> Msg + Send + Sync + 'static
+    {
+        Box::new(AdaptedRef {
+            actor: self.clone(),
+            f: Arc::new(f)
+        })
+    }
 }
 
 impl<Msg: Message> ActorReference for ActorRef<Msg> {
@@ -475,7 +564,7 @@ impl<Msg: Message> fmt::Display for ActorRef<Msg> {
 
 impl<Msg: Message> PartialEq for ActorRef<Msg> {
     fn eq(&self, other: &ActorRef<Msg>) -> bool {
-        self.uri().path == other.uri().path
+        self.uri() == other.uri()
     }
 }
 
@@ -504,3 +593,74 @@ pub trait TmpActorRefFactory {
                         -> Result<ActorRef<A::Msg>, CreateError>
     where A: Actor;
 }
+
+/// An `ActorRef` that converts messages of type `T` before sending.
+/// 
+/// Created using `ActorRef::adapt`.
+struct AdaptedRef<T, Msg: Message> {
+    actor: ActorRef<Msg>,
+    f: Arc<dyn Fn(T) -> Msg + Send + Sync>,
+}
+
+impl<T, Msg: Message> Clone for AdaptedRef<T, Msg> {
+    fn clone(&self) -> Self {
+        AdaptedRef {
+            actor: self.actor.clone(),
+            f: self.f.clone()
+        }
+    }
+}
+
+impl<T, Msg> Tell<T> for AdaptedRef<T, Msg>
+    where T: Message, Msg: Message
+{
+    fn tell(&self, msg: T, sender: Sender) {
+        self.actor.send_msg((self.f)(msg), sender);
+    }
+
+    fn box_clone(&self) -> BoxedTell<T> {
+        Box::new(self.clone())
+    }
+}
+
+impl<T, Msg: Message> ActorReference for AdaptedRef<T, Msg> {
+    fn name(&self) -> &str {
+        self.actor.name()
+    }
+
+    fn uri(&self) -> &ActorUri {
+        self.actor.uri()
+    }
+
+    fn path(&self) -> &ActorPath {
+        self.actor.path()
+    }
+
+    fn is_root(&self) -> bool {
+        self.actor.is_root()
+    }
+
+    fn parent(&self) -> BasicActorRef {
+        self.actor.parent()
+    }
+
+    fn user_root(&self) -> BasicActorRef {
+        self.actor.user_root()
+    }
+
+    fn has_children(&self) -> bool {
+        self.actor.has_children()
+    }
+
+    fn is_child(&self, actor: &BasicActorRef) -> bool {
+        self.actor.is_child(actor)
+    }
+
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = BasicActorRef> + 'a> {
+        self.actor.children()
+    }
+
+    fn sys_tell(&self, msg: SystemMsg) {
+        self.actor.sys_tell(msg)
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    pin::Pin,
+    task::{self, Poll}
+};
+
+use futures::Sink;
+
+use crate::{
+    Message,
+    actor::{ActorRef, SendError, TellError}
+};
+
+impl<Msg> ActorRef<Msg>
+    where Msg: Message
+{
+    /// Returns a `Sink` that sends messages to this actor
+    ///
+    /// See `ActorSink`.
+    pub fn sink(&self) -> ActorSink<Msg> {
+        ActorSink {
+            actor: self.clone(),
+            pending: None
+        }
+    }
+}
+
+/// A `Sink` that sends messages to an actor with backpressure
+///
+/// The sink is ready when the actor's mailbox has space, so forwarding
+/// a stream to an actor with a bounded mailbox (`mailbox.capacity`)
+/// waits for the actor to process messages instead of overflowing its
+/// mailbox. With an unbounded mailbox the sink is always ready.
+///
+/// Messages are sent without a sender. The sink fails with
+/// `TellError::Terminated` if the actor has terminated.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// use futures::{executor::block_on, stream, StreamExt};
+///
+/// struct Counter;
+///
+/// impl Actor for Counter {
+///     type Msg = u32;
+///
+///     fn recv(&mut self,
+///             _ctx: &Context<Self::Msg>,
+///             msg: Self::Msg,
+///             _sender: Sender) {
+///         println!("{}", msg);
+///     }
+/// }
+///
+/// let sys = ActorSystem::new().unwrap();
+/// let actor = sys.actor_of(Props::new(|| Counter), "counter").unwrap();
+///
+/// let numbers = stream::iter(0..100u32).map(Ok);
+/// block_on(numbers.forward(actor.sink())).unwrap();
+/// ```
+pub struct ActorSink<Msg: Message> {
+    actor: ActorRef<Msg>,
+    // a message that didn't fit because another sender filled the mailbox
+    pending: Option<Msg>,
+}
+
+impl<Msg> ActorSink<Msg>
+    where Msg: Message
+{
+    /// Sends the pending message once the mailbox has space
+    fn poll_pending(&mut self, cx: &mut task::Context<'_>) -> Poll<Result<(), TellError>> {
+        while let Some(msg) = self.pending.take() {
+            if self.actor.cell.poll_mailbox_ready(cx).is_pending() {
+                self.pending = Some(msg);
+                return Poll::Pending;
+            }
+
+            self.send(msg)?;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn send(&mut self, msg: Msg) -> Result<(), TellError> {
+        match self.actor.try_send(msg, None) {
+            Ok(()) => Ok(()),
+            Err(SendError { msg, reason: TellError::MailboxFull }) => {
+                self.pending = Some(msg);
+                Ok(())
+            }
+            Err(e) => Err(e.reason)
+        }
+    }
+}
+
+// the pending message is never pinned
+impl<Msg: Message> Unpin for ActorSink<Msg> {}
+
+impl<Msg> Sink<Msg> for ActorSink<Msg>
+    where Msg: Message
+{
+    type Error = TellError;
+
+    fn poll_ready(mut self: Pin<&mut Self>,
+                    cx: &mut task::Context<'_>)
+                    -> Poll<Result<(), Self::Error>> {
+        match self.poll_pending(cx) {
+            Poll::Ready(Ok(())) => {
+                self.actor.cell.poll_mailbox_ready(cx).map(Ok)
+            }
+            res => res
+        }
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, msg: Msg) -> Result<(), Self::Error> {
+        self.send(msg)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>,
+                    cx: &mut task::Context<'_>)
+                    -> Poll<Result<(), Self::Error>> {
+        self.poll_pending(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>,
+                    cx: &mut task::Context<'_>)
+                    -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
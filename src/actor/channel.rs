@@ -1,22 +1,27 @@
 #![allow(unused_variables)]
 
 use std::{
-    collections::HashMap,
-    hash::{Hash, Hasher}
+    fmt,
+    collections::{HashMap, VecDeque},
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex}
 };
 
+use log::warn;
+
 use crate::{
-    Message,
-    system::{SystemMsg, SystemEvent},
+    Message, AnyMessage,
+    system::{SystemMsg, SystemEvent, ShutdownPhase},
     actor::{
         BoxedTell, Actor, Props, BoxActorProd, CreateError, Sender,
-        ActorReference, ActorRef, BasicActorRef, Context, Receive, ActorRefFactory
+        ActorReference, ActorRef, BasicActorRef, Context, Receive, ActorRefFactory,
+        ActorUri, ActorPath, Tell, TellError,
+        subscriptions::{Subscriptions, topic_matches}
     }
 };
 
 
-type Subs<Msg> = HashMap<Topic, Vec<BoxedTell<Msg>>>;
-
 /// A specialized actor for providing Publish/Subscribe capabilities to users.
 /// 
 
@@ -28,21 +33,133 @@ pub type ChannelRef<Msg> = ActorRef<ChannelMsg<Msg>>;
 
 /// A specialized actor for providing Publish/Subscribe capabilities for user level messages
 pub struct Channel<Msg: Message> {
-    subs: Subs<Msg>,
+    subs: Subscriptions<Msg>,
+    config: ChannelConfig,
+    retained: HashMap<Topic, VecDeque<(Msg, Sender)>>,
 }
 
 impl<Msg> Channel<Msg>
     where Msg: Message
 {
     pub fn new() -> Self {
+        Channel::with_config(ChannelConfig::new())
+    }
+
+    pub fn with_config(config: ChannelConfig) -> Self {
         Channel {
-            subs: HashMap::new()
+            subs: Subscriptions::new(),
+            config,
+            retained: HashMap::new()
         }
     }
 
     pub fn props() -> BoxActorProd<Channel<Msg>> {
         Props::new(Channel::new)
     }
+
+    pub fn props_with_config(config: ChannelConfig) -> BoxActorProd<Channel<Msg>> {
+        Props::new_args(Channel::with_config, config)
+    }
+
+    fn retain(&mut self, msg: &Publish<Msg>, sender: &Sender) {
+        if self.config.retain == 0 {
+            return;
+        }
+
+        let retained = self.retained
+            .entry(msg.topic.clone())
+            .or_default();
+
+        if retained.len() == self.config.retain {
+            retained.pop_front();
+        }
+        retained.push_back((msg.msg.clone(), sender.clone()));
+    }
+
+    fn subscribe(&mut self,
+                topic: &Topic,
+                actor: BoxedTell<Msg>,
+                filter: Option<Filter<Msg>>) {
+        self.replay(topic, &actor, filter.as_ref());
+        self.subs.subscribe(topic, actor, filter);
+    }
+
+    fn replay(&self,
+                topic: &Topic,
+                actor: &BoxedTell<Msg>,
+                filter: Option<&Filter<Msg>>) {
+        let retained = self.retained
+            .iter()
+            .filter(|(t, _)| topic_matches(topic, t));
+
+        for (_, msgs) in retained {
+            let msgs = msgs.iter()
+                .filter(|(msg, _)| {
+                    match filter {
+                        Some(filter) => filter(msg),
+                        None => true
+                    }
+                });
+
+            for (msg, sender) in msgs {
+                actor.tell(msg.clone(), sender.clone());
+            }
+        }
+    }
+}
+
+/// Channel configuration
+/// 
+/// A channel can retain the last messages published to each topic.
+/// When an actor subscribes, the retained messages of the topics
+/// matching its subscription are sent to it immediately, in the order
+/// they were published. Retaining a single message gives each topic a
+/// last known value, like MQTT retained messages.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use riker::actors::*;
+/// let sys = ActorSystem::new().unwrap();
+/// 
+/// // new subscribers receive the last 10 messages of each topic
+/// let config = ChannelConfig::new().retain(10);
+/// let chan: ChannelRef<String> = channel_with_config("my-chan", &sys, config).unwrap();
+/// ```
+/// 
+/// A channel with many subscribers can be split into shards, see
+/// `ShardedChannel`.
+#[derive(Clone, Debug)]
+pub struct ChannelConfig {
+    retain: usize,
+    shards: usize,
+}
+
+impl ChannelConfig {
+    /// Creates a config for a single shard channel that doesn't retain messages
+    pub fn new() -> Self {
+        ChannelConfig {
+            retain: 0,
+            shards: 1
+        }
+    }
+
+    /// Number of messages to retain per topic. Default is 0.
+    pub fn retain(self, retain: usize) -> Self {
+        ChannelConfig { retain, ..self }
+    }
+
+    /// Number of shards a `ShardedChannel` partitions subscribers across.
+    /// Default is 1. Ignored by `Channel`.
+    pub fn shards(self, shards: usize) -> Self {
+        ChannelConfig { shards, ..self }
+    }
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        ChannelConfig::new()
+    }
 }
 
 impl<Msg> Actor for Channel<Msg>
@@ -50,15 +167,18 @@ impl<Msg> Actor for Channel<Msg>
 {
     type Msg = ChannelMsg<Msg>;
 
-    // todo subscribe to events to unsub subscribers when they die
     fn pre_start(&mut self, ctx: &ChannelCtx<Msg>) {
-        // let sub = Subscribe {
-        //     topic: SysTopic::ActorTerminated.into(),
-        //     actor: Box::new(ctx.myself.clone())//.into()
-        // };
-
-        // let msg = ChannelMsg::Subscribe(sub);
-        // ctx.myself.tell(msg, None);
+        // subscribe to ActorTerminated events to unsubscribe subscribers
+        // when they terminate. The system channels are created before
+        // the events channel is available and are subscribed by the system.
+        if let Some(chans) = ctx.system.sys_channels.as_ref() {
+            let sub = Subscribe {
+                topic: SysTopic::ActorTerminated.into(),
+                actor: Box::new(EventSub(ctx.myself().into()))
+            };
+
+            chans.sys_events.tell(sub, None);
+        }
     }
 
     fn recv(&mut self,
@@ -79,11 +199,7 @@ impl<Msg> Actor for Channel<Msg>
         if let SystemMsg::Event(evt) = msg {
             match evt {
                 SystemEvent::ActorTerminated(terminated) => {
-                    let subs = self.subs.clone();
-
-                    for topic in subs.keys() {
-                        unsubscribe(&mut self.subs, topic, &terminated.actor);
-                    }
+                    self.subs.unsubscribe_all(&terminated.actor);
                 }
                 _ => {}
             }
@@ -104,6 +220,7 @@ impl<Msg> Receive<ChannelMsg<Msg>> for Channel<Msg>
         match msg {
             ChannelMsg::Publish(p) => self.receive(ctx, p, sender),
             ChannelMsg::Subscribe(sub) => self.receive(ctx, sub, sender),
+            ChannelMsg::SubscribeFiltered(sub) => self.receive(ctx, sub, sender),
             ChannelMsg::Unsubscribe(unsub) => self.receive(ctx, unsub, sender),
             ChannelMsg::UnsubscribeAll(unsub) => self.receive(ctx, unsub, sender),
         }
@@ -120,11 +237,21 @@ impl<Msg> Receive<Subscribe<Msg>> for Channel<Msg>
                 msg: Subscribe<Msg>,
                 sender: Sender) {
         
-        if self.subs.contains_key(&msg.topic) {
-            self.subs.get_mut(&msg.topic).unwrap().push(msg.actor);
-        } else {
-            self.subs.insert(msg.topic, vec![msg.actor]);
-        }
+        self.subscribe(&msg.topic, msg.actor, None);
+    }
+}
+
+impl<Msg> Receive<SubscribeFiltered<Msg>> for Channel<Msg>
+    where Msg: Message
+{
+    type Msg = ChannelMsg<Msg>;
+
+    fn receive(&mut self,
+                ctx: &ChannelCtx<Msg>,
+                msg: SubscribeFiltered<Msg>,
+                sender: Sender) {
+        
+        self.subscribe(&msg.topic, msg.actor, Some(msg.filter));
     }
 }
 
@@ -138,7 +265,7 @@ impl<Msg> Receive<Unsubscribe<Msg>> for Channel<Msg>
                 msg: Unsubscribe<Msg>,
                 sender: Sender) {
         
-        unsubscribe(&mut self.subs, &msg.topic, &msg.actor);
+        self.subs.unsubscribe(&msg.topic, &msg.actor);
     }
 }
 
@@ -152,11 +279,7 @@ impl<Msg> Receive<UnsubscribeAll<Msg>> for Channel<Msg>
                 msg: UnsubscribeAll<Msg>,
                 sender: Sender) {
         
-        let subs = self.subs.clone();
-
-        for topic in subs.keys() {
-            unsubscribe(&mut self.subs, topic, &msg.actor);
-        }
+        self.subs.unsubscribe_all(&msg.actor);
     }
 }
 
@@ -170,30 +293,213 @@ impl<Msg> Receive<Publish<Msg>> for Channel<Msg>
                 msg: Publish<Msg>,
                 sender: Sender) {
                     
-        // send system event to actors subscribed to all topics
-        if let Some(subs) = self.subs.get(&All.into()) {
-            for sub in subs.iter() {
-                sub.tell(msg.msg.clone(), sender.clone());
+        // send message to actors subscribed to matching topics
+        for sub in self.subs.matches(&msg.topic, &msg.msg) {
+            sub.tell(msg.msg.clone(), sender.clone());
+        }
+
+        self.retain(&msg, &sender);
+    }
+}
+
+/// A channel that partitions its subscribers across multiple shards
+/// 
+/// A `Channel` is a single actor, so publishing to a large number of
+/// subscribers sends every message from one mailbox thread. A sharded
+/// channel creates a child `Channel` per shard and assigns each
+/// subscriber to a shard by its path. A publish is forwarded to every
+/// shard and the shards send to their subscribers in parallel.
+/// 
+/// A published message is wrapped in an `Arc` once by the channel and
+/// subscribers receive `Arc<Msg>`, so the payload is shared rather than
+/// cloned for each shard and subscriber. Subscriptions are therefore
+/// made with actors accepting `Arc<Msg>`.
+/// 
+/// Subscribers are always assigned to the same shard, so unsubscribing
+/// works as with a single channel. Retained messages are kept by each
+/// shard. Messages from the same publisher are received in the order
+/// they were published, but subscribers on different shards can
+/// receive them at different times.
+/// 
+/// Sharded channels are created using `sharded_channel`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use riker::actors::*;
+/// use std::sync::Arc;
+/// 
+/// #[derive(Clone, Debug)]
+/// struct Quotes(Vec<f64>);
+/// 
+/// struct Trader;
+/// 
+/// impl Actor for Trader {
+///     type Msg = Arc<Quotes>;
+/// 
+///     fn recv(&mut self,
+///             _ctx: &Context<Self::Msg>,
+///             msg: Self::Msg,
+///             _sender: Sender) {
+///         println!("{:?}", msg.0);
+///     }
+/// }
+/// 
+/// let sys = ActorSystem::new().unwrap();
+/// 
+/// let config = ChannelConfig::new().shards(8);
+/// let chan: ShardedChannelRef<Quotes> = sharded_channel("quotes", &sys, config).unwrap();
+/// 
+/// let trader = sys.actor_of(Props::new(|| Trader), "trader").unwrap();
+/// chan.tell(Subscribe { actor: Box::new(trader), topic: "fx".into() }, None);
+/// 
+/// chan.tell(Publish { msg: Quotes(vec![1.0, 1.5]), topic: "fx".into() }, None);
+/// ```
+pub struct ShardedChannel<Msg: Message + Sync> {
+    config: ChannelConfig,
+    shards: Vec<ChannelRef<Arc<Msg>>>,
+}
+
+pub type ShardedChannelCtx<Msg> = Context<ShardedChannelMsg<Msg>>;
+pub type ShardedChannelRef<Msg> = ActorRef<ShardedChannelMsg<Msg>>;
+
+impl<Msg> ShardedChannel<Msg>
+    where Msg: Message + Sync
+{
+    pub fn new(config: ChannelConfig) -> Self {
+        ShardedChannel {
+            config,
+            shards: Vec::new()
+        }
+    }
+
+    pub fn props(config: ChannelConfig) -> BoxActorProd<ShardedChannel<Msg>> {
+        Props::new_args(ShardedChannel::new, config)
+    }
+
+    fn shard(&self, actor: &dyn ActorReference) -> Option<&ChannelRef<Arc<Msg>>> {
+        if self.shards.is_empty() {
+            return None;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        actor.path().hash(&mut hasher);
+        let i = hasher.finish() as usize % self.shards.len();
+
+        Some(&self.shards[i])
+    }
+}
+
+impl<Msg> Actor for ShardedChannel<Msg>
+    where Msg: Message + Sync
+{
+    type Msg = ShardedChannelMsg<Msg>;
+
+    fn pre_start(&mut self, ctx: &ShardedChannelCtx<Msg>) {
+        let config = self.config.clone().shards(1);
+
+        for i in 0..self.config.shards.max(1) {
+            let props = Channel::props_with_config(config.clone());
+
+            match ctx.actor_of(props, &format!("shard-{}", i)) {
+                Ok(shard) => self.shards.push(shard),
+                Err(e) => {
+                    // a channel missing a shard would lose subscribers
+                    warn!("Channel {} failed to create shard {}: {}", ctx.myself.path(), i, e);
+                    self.shards.clear();
+                    ctx.stop(&ctx.myself);
+                    return;
+                }
             }
         }
+    }
+
+    fn recv(&mut self,
+            ctx: &ShardedChannelCtx<Msg>,
+            msg: ShardedChannelMsg<Msg>,
+            sender: Sender) {
 
-        // send system event to actors subscribed to the topic
-        if let Some(subs) = self.subs.get(&msg.topic) {
-            for sub in subs.iter() {
-                sub.tell(msg.msg.clone(), sender.clone());
+        match msg {
+            ShardedChannelMsg::Publish(p) => {
+                // shards and subscribers only clone the pointer
+                let msg = Arc::new(p.msg);
+                for shard in self.shards.iter() {
+                    let publish = Publish { topic: p.topic.clone(), msg: msg.clone() };
+                    shard.tell(publish, sender.clone());
+                }
+            }
+            ShardedChannelMsg::Subscribe(sub) => {
+                if let Some(shard) = self.shard(&sub.actor) {
+                    shard.tell(sub, sender);
+                }
             }
-        }  
+            ShardedChannelMsg::SubscribeFiltered(sub) => {
+                if let Some(shard) = self.shard(&sub.actor) {
+                    shard.tell(sub, sender);
+                }
+            }
+            ShardedChannelMsg::Unsubscribe(unsub) => {
+                if let Some(shard) = self.shard(&unsub.actor) {
+                    shard.tell(unsub, sender);
+                }
+            }
+            ShardedChannelMsg::UnsubscribeAll(unsub) => {
+                if let Some(shard) = self.shard(&unsub.actor) {
+                    shard.tell(unsub, sender);
+                }
+            }
+        }
     }
 }
 
-fn unsubscribe<Msg>(subs: &mut Subs<Msg>,
-                    topic: &Topic,
-                    actor: &dyn ActorReference) {
-    // Nightly only: self.subs.get(msg_type).unwrap().remove_item(actor);
-    if subs.contains_key(topic) {
-        if let Some(pos) = subs.get(topic).unwrap().iter().position(|x| x.path() == actor.path()) {
-            subs.get_mut(topic).unwrap().remove(pos);
-        }
+/// Messages accepted by a `ShardedChannel`
+/// 
+/// Messages are published as `Msg` and subscribers receive `Arc<Msg>`.
+#[derive(Debug, Clone)]
+pub enum ShardedChannelMsg<Msg: Message + Sync> {
+    /// Publish message
+    Publish(Publish<Msg>),
+
+    /// Subscribe given `ActorRef` to a topic on a channel
+    Subscribe(Subscribe<Arc<Msg>>),
+
+    /// Subscribe given `ActorRef` to a topic on a channel with a filter
+    SubscribeFiltered(SubscribeFiltered<Arc<Msg>>),
+
+    /// Unsubscribe the given `ActorRef` from a topic on a channel
+    Unsubscribe(Unsubscribe<Arc<Msg>>),
+
+    /// Unsubscribe the given `ActorRef` from all topics on a channel
+    UnsubscribeAll(UnsubscribeAll<Arc<Msg>>),
+}
+
+impl<Msg: Message + Sync> Into<ShardedChannelMsg<Msg>> for Publish<Msg> {
+    fn into(self) -> ShardedChannelMsg<Msg> {
+        ShardedChannelMsg::Publish(self)
+    }
+}
+
+impl<Msg: Message + Sync> Into<ShardedChannelMsg<Msg>> for Subscribe<Arc<Msg>> {
+    fn into(self) -> ShardedChannelMsg<Msg> {
+        ShardedChannelMsg::Subscribe(self)
+    }
+}
+
+impl<Msg: Message + Sync> Into<ShardedChannelMsg<Msg>> for SubscribeFiltered<Arc<Msg>> {
+    fn into(self) -> ShardedChannelMsg<Msg> {
+        ShardedChannelMsg::SubscribeFiltered(self)
+    }
+}
+
+impl<Msg: Message + Sync> Into<ShardedChannelMsg<Msg>> for Unsubscribe<Arc<Msg>> {
+    fn into(self) -> ShardedChannelMsg<Msg> {
+        ShardedChannelMsg::Unsubscribe(self)
+    }
+}
+
+impl<Msg: Message + Sync> Into<ShardedChannelMsg<Msg>> for UnsubscribeAll<Arc<Msg>> {
+    fn into(self) -> ShardedChannelMsg<Msg> {
+        ShardedChannelMsg::UnsubscribeAll(self)
     }
 }
 
@@ -246,6 +552,7 @@ impl Receive<ChannelMsg<SystemEvent>> for EventsChannel {
         match msg {
             ChannelMsg::Publish(p) => self.receive(ctx, p, sender),
             ChannelMsg::Subscribe(sub) => self.0.receive(ctx, sub, sender),
+            ChannelMsg::SubscribeFiltered(sub) => self.0.receive(ctx, sub, sender),
             ChannelMsg::Unsubscribe(unsub) => self.0.receive(ctx, unsub, sender),
             ChannelMsg::UnsubscribeAll(unsub) => self.0.receive(ctx, unsub, sender),
         }
@@ -260,40 +567,111 @@ impl Receive<Publish<SystemEvent>> for EventsChannel {
                 msg: Publish<SystemEvent>,
                 sender: Sender) {
                     
-        // send system event to actors subscribed to all topics
-        if let Some(subs) = self.0.subs.get(&All.into()) {
-            for sub in subs.iter() {
-                let evt = SystemMsg::Event(msg.msg.clone());
-                sub.sys_tell(evt);
-            }
+        // send system event to actors subscribed to matching topics
+        for sub in self.0.subs.matches(&msg.topic, &msg.msg) {
+            let evt = SystemMsg::Event(msg.msg.clone());
+            sub.sys_tell(evt);
         }
-
-        // send system event to actors subscribed to the topic
-        if let Some(subs) = self.0.subs.get(&msg.topic) {
-            for sub in subs.iter() {
-                let evt = SystemMsg::Event(msg.msg.clone());
-                sub.sys_tell(evt);
-            }
-        }  
     }
 }
 
 // Deadletter channel implementations
 pub type DLChannelMsg = ChannelMsg<DeadLetter>;
 
+/// A message that could not be delivered
+/// 
+/// `msg` is the `Debug` representation of the message and `msg_type`
+/// its type name. The original message is kept in `payload` and can be
+/// retrieved with `payload()`, so that subscribers can retry delivery,
+/// forward it to another actor or persist it.
 #[derive(Clone, Debug)]
 pub struct DeadLetter {
     pub msg: String,
+    pub msg_type: &'static str,
+    pub payload: Arc<Mutex<AnyMessage>>,
+    pub reason: DeadLetterReason,
     pub sender: Sender,
     pub recipient: BasicActorRef,
 }
 
+impl DeadLetter {
+    pub fn new<Msg: Message>(msg: Msg,
+                            reason: DeadLetterReason,
+                            sender: Sender,
+                            recipient: BasicActorRef) -> Self {
+        DeadLetter {
+            msg: format!("{:?}", msg),
+            msg_type: std::any::type_name::<Msg>(),
+            // not one-time, since each subscriber receives a clone
+            payload: Arc::new(Mutex::new(AnyMessage::new(msg, false))),
+            reason,
+            sender,
+            recipient
+        }
+    }
+
+    /// Returns a copy of the original message if it is a `T`
+    pub fn payload<T: Message>(&self) -> Option<T> {
+        self.payload.lock().unwrap().take().ok()
+    }
+}
+
+/// The reason a message was sent to dead letters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// The recipient was terminated
+    Terminated,
+    /// The recipient's mailbox was full
+    MailboxFull,
+    /// The recipient doesn't accept messages of this type
+    UnsupportedType,
+    /// No actors matched the actor selection
+    NoSelectionMatch,
+    /// The router had no routees
+    NoRoutees,
+}
+
+impl From<TellError> for DeadLetterReason {
+    fn from(err: TellError) -> Self {
+        match err {
+            TellError::UnsupportedType => DeadLetterReason::UnsupportedType,
+            TellError::Terminated => DeadLetterReason::Terminated,
+            TellError::MailboxFull => DeadLetterReason::MailboxFull
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Subscribe<Msg: Message> {
     pub topic: Topic,
     pub actor: BoxedTell<Msg>,
 }
 
+/// Subscribes an actor to a topic, sending it only the messages
+/// for which `filter` returns true
+/// 
+/// The filter is evaluated by the channel, before the message is sent
+/// to the actor's mailbox. The subscription is removed by `Unsubscribe`
+/// like any other.
+#[derive(Clone)]
+pub struct SubscribeFiltered<Msg: Message> {
+    pub topic: Topic,
+    pub actor: BoxedTell<Msg>,
+    pub filter: Filter<Msg>,
+}
+
+/// A predicate used to filter the messages sent to a subscriber
+pub type Filter<Msg> = Arc<dyn Fn(&Msg) -> bool + Send + Sync>;
+
+impl<Msg: Message> fmt::Debug for SubscribeFiltered<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SubscribeFiltered")
+            .field("topic", &self.topic)
+            .field("actor", &self.actor)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Unsubscribe<Msg: Message> {
     pub topic: Topic,
@@ -319,6 +697,9 @@ pub enum ChannelMsg<Msg: Message> {
     /// Subscribe given `ActorRef` to a topic on a channel
     Subscribe(Subscribe<Msg>),
 
+    /// Subscribe given `ActorRef` to a topic on a channel with a filter
+    SubscribeFiltered(SubscribeFiltered<Msg>),
+
     /// Unsubscribe the given `ActorRef` from a topic on a channel
     Unsubscribe(Unsubscribe<Msg>),
 
@@ -340,6 +721,13 @@ impl<Msg: Message> Into<ChannelMsg<Msg>> for Subscribe<Msg> {
     }
 }
 
+// subscribe with a filter
+impl<Msg: Message> Into<ChannelMsg<Msg>> for SubscribeFiltered<Msg> {
+    fn into(self) -> ChannelMsg<Msg> {
+        ChannelMsg::SubscribeFiltered(self)
+    }
+}
+
 // unsubscribe
 impl<Msg: Message> Into<ChannelMsg<Msg>> for Unsubscribe<Msg> {
     fn into(self) -> ChannelMsg<Msg> {
@@ -357,9 +745,21 @@ impl<Msg: Message> Into<ChannelMsg<Msg>> for UnsubscribeAll<Msg> {
 // Topics allow channel subscribers to filter messages by interest
 /// 
 /// When publishing a message to a channel a Topic is provided.
+/// 
+/// Topics are hierarchical, with segments separated by `.`, e.g.
+/// `orders.eu.created`. When subscribing, `*` matches exactly one
+/// segment and `#` matches zero or more segments, so `orders.*.created`
+/// and `orders.#` both match `orders.eu.created`. Subscribing to `*`
+/// alone (`All`) matches every topic.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Topic(String);
 
+impl Topic {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 impl Eq for Topic {}
 
 impl Hash for Topic {
@@ -385,7 +785,18 @@ impl<'a> From<&'a SystemEvent> for Topic {
         match evt {
             &SystemEvent::ActorCreated(_) => Topic::from("actor.created"),
             &SystemEvent::ActorTerminated(_) => Topic::from("actor.terminated"),
-            &SystemEvent::ActorRestarted(_) => Topic::from("actor.restarted")
+            &SystemEvent::ActorRestarted(_) => Topic::from("actor.restarted"),
+            &SystemEvent::RouterResized(_) => Topic::from("router.resized"),
+            &SystemEvent::ActorFailed(_) => Topic::from("actor.failed"),
+            &SystemEvent::SupervisorDecision(_) => Topic::from("supervisor.decision"),
+            &SystemEvent::ActorFailedToStart(_) => Topic::from("actor.start_failed"),
+            &SystemEvent::MailboxOverflow(_) => Topic::from("mailbox.overflow"),
+            &SystemEvent::SystemShutdown(ref evt) => {
+                match evt.phase {
+                    ShutdownPhase::Started => Topic::from("system.shutdown.started"),
+                    ShutdownPhase::Completed => Topic::from("system.shutdown.completed")
+                }
+            }
         }
     }
 }
@@ -404,6 +815,12 @@ pub enum SysTopic {
     ActorCreated,
     ActorTerminated,
     ActorRestarted,
+    RouterResized,
+    ActorFailed,
+    SupervisorDecision,
+    ActorFailedToStart,
+    MailboxOverflow,
+    SystemShutdown,
 }
 
 impl From<SysTopic> for Topic {
@@ -411,14 +828,99 @@ impl From<SysTopic> for Topic {
         match evt {
             SysTopic::ActorCreated => Topic::from("actor.created"),
             SysTopic::ActorTerminated => Topic::from("actor.terminated"),
-            SysTopic::ActorRestarted => Topic::from("actor.restarted")
+            SysTopic::ActorRestarted => Topic::from("actor.restarted"),
+            SysTopic::RouterResized => Topic::from("router.resized"),
+            SysTopic::ActorFailed => Topic::from("actor.failed"),
+            SysTopic::SupervisorDecision => Topic::from("supervisor.decision"),
+            SysTopic::ActorFailedToStart => Topic::from("actor.start_failed"),
+            SysTopic::MailboxOverflow => Topic::from("mailbox.overflow"),
+            // all shutdown phases
+            SysTopic::SystemShutdown => Topic::from("system.shutdown.*")
         }
     }
 }
 
+/// Subscribes an actor to system events regardless of its message type
+///
+/// System events are delivered by the events channel as system messages
+/// and are received in the actor's `sys_recv`, so any actor can subscribe
+/// to them, not only actors whose `Msg` supports `SystemEvent`.
+#[derive(Clone)]
+pub(crate) struct EventSub(pub BasicActorRef);
+
+impl ActorReference for EventSub {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn uri(&self) -> &ActorUri {
+        self.0.uri()
+    }
+
+    fn path(&self) -> &ActorPath {
+        self.0.path()
+    }
+
+    fn is_root(&self) -> bool {
+        self.0.is_root()
+    }
+
+    fn parent(&self) -> BasicActorRef {
+        self.0.parent()
+    }
+
+    fn user_root(&self) -> BasicActorRef {
+        self.0.user_root()
+    }
+
+    fn has_children(&self) -> bool {
+        self.0.has_children()
+    }
+
+    fn is_child(&self, actor: &BasicActorRef) -> bool {
+        self.0.is_child(actor)
+    }
+
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = BasicActorRef> + 'a> {
+        self.0.children()
+    }
+
+    fn sys_tell(&self, msg: SystemMsg) {
+        self.0.sys_tell(msg)
+    }
+}
+
+impl Tell<SystemEvent> for EventSub {
+    fn tell(&self, msg: SystemEvent, _: Sender) {
+        self.0.sys_tell(SystemMsg::Event(msg));
+    }
+
+    fn box_clone(&self) -> BoxedTell<SystemEvent> {
+        Box::new(self.clone())
+    }
+}
+
 pub fn channel<Msg>(name: &str, fact: &impl ActorRefFactory)
                     -> Result<ChannelRef<Msg>, CreateError>
     where Msg: Message
 {
     fact.actor_of(Channel::<Msg>::props(), name)
 }
+
+pub fn channel_with_config<Msg>(name: &str,
+                                fact: &impl ActorRefFactory,
+                                config: ChannelConfig)
+                                -> Result<ChannelRef<Msg>, CreateError>
+    where Msg: Message
+{
+    fact.actor_of(Channel::<Msg>::props_with_config(config), name)
+}
+
+pub fn sharded_channel<Msg>(name: &str,
+                            fact: &impl ActorRefFactory,
+                            config: ChannelConfig)
+                            -> Result<ShardedChannelRef<Msg>, CreateError>
+    where Msg: Message + Sync
+{
+    fact.actor_of(ShardedChannel::<Msg>::props(config), name)
+}
@@ -0,0 +1,182 @@
+use std::{
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering}
+    },
+    task::{self, Poll}
+};
+
+use futures::{
+    Stream, StreamExt,
+    channel::mpsc
+};
+
+use crate::{
+    Message,
+    actor::{
+        Actor, ActorRef, ActorRefFactory, TmpActorRefFactory, ChannelMsg, ChannelRef,
+        Context, CreateError, Props, Sender, Subscribe, Tell, Topic, Unsubscribe
+    },
+    system::ActorSystem
+};
+
+/// Number of messages a `ChannelStream` buffers by default
+const STREAM_BUFFER: usize = 128;
+
+impl<Msg> ActorRef<ChannelMsg<Msg>>
+    where Msg: Message
+{
+    /// Returns a stream of the messages published to topics matching `topic`
+    ///
+    /// Buffers up to 128 messages, see `stream_with_buffer`.
+    pub fn stream(&self, topic: impl Into<Topic>) -> Result<ChannelStream<Msg>, CreateError> {
+        self.stream_with_buffer(topic, STREAM_BUFFER)
+    }
+
+    /// Returns a stream of the messages published to topics matching `topic`
+    ///
+    /// Up to `buffer` messages are buffered while the stream isn't
+    /// polled. Messages published while the buffer is full are dropped
+    /// and counted by `ChannelStream::dropped`.
+    /// 
+    /// Returns an error if the stream's actor can't be created, e.g.
+    /// while the system is shutting down.
+    pub fn stream_with_buffer(&self,
+                            topic: impl Into<Topic>,
+                            buffer: usize)
+                            -> Result<ChannelStream<Msg>, CreateError> {
+        let (tx, rx) = mpsc::channel(buffer);
+        let dropped = Arc::new(AtomicUsize::new(0));
+
+        let sys = self.cell.system().clone();
+        let props = Props::new_args(StreamBridge::new, (tx, dropped.clone()));
+        let bridge = sys.tmp_actor_of(props)?;
+
+        // subscribe here rather than in the bridge's `pre_start` so that
+        // messages published after this returns are received
+        let topic = topic.into();
+        let sub = Subscribe {
+            topic: topic.clone(),
+            actor: Box::new(bridge.clone())
+        };
+        self.tell(sub, None);
+
+        Ok(ChannelStream {
+            rx,
+            dropped,
+            bridge,
+            chan: self.clone(),
+            topic,
+            sys
+        })
+    }
+}
+
+/// A stream of the messages published to a channel
+///
+/// Allows code outside of actors, such as futures started with
+/// `Run::run`, to consume channel publications. A temporary actor
+/// subscribes to the channel and forwards messages to the stream.
+/// Dropping the stream unsubscribes and stops the actor.
+///
+/// Buffering is bounded. If the stream falls behind and its buffer
+/// fills up, new messages are dropped rather than held in memory, and
+/// `dropped` returns how many were lost.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// use futures::StreamExt;
+///
+/// let sys = ActorSystem::new().unwrap();
+/// let chan: ChannelRef<String> = channel("my-chan", &sys).unwrap();
+///
+/// let mut stream = chan.stream("my-topic").unwrap();
+/// chan.tell(Publish { msg: "hello".into(), topic: "my-topic".into() }, None);
+///
+/// let handle = sys.run(async move {
+///     stream.next().await
+/// }).unwrap();
+///
+/// let msg = futures::executor::block_on(handle);
+/// assert_eq!(msg, Some("hello".to_string()));
+/// ```
+pub struct ChannelStream<Msg: Message> {
+    rx: mpsc::Receiver<Msg>,
+    dropped: Arc<AtomicUsize>,
+    bridge: ActorRef<Msg>,
+    chan: ChannelRef<Msg>,
+    topic: Topic,
+    sys: ActorSystem,
+}
+
+impl<Msg> ChannelStream<Msg>
+    where Msg: Message
+{
+    /// Number of messages dropped because the buffer was full
+    pub fn dropped(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<Msg> Stream for ChannelStream<Msg>
+    where Msg: Message
+{
+    type Item = Msg;
+
+    fn poll_next(mut self: Pin<&mut Self>,
+                cx: &mut task::Context<'_>)
+                -> Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl<Msg> Drop for ChannelStream<Msg>
+    where Msg: Message
+{
+    fn drop(&mut self) {
+        let unsub = Unsubscribe {
+            topic: self.topic.clone(),
+            actor: Box::new(self.bridge.clone())
+        };
+        self.chan.tell(unsub, None);
+        self.sys.stop(&self.bridge);
+    }
+}
+
+/// Forwards channel messages to a `ChannelStream`
+struct StreamBridge<Msg: Message> {
+    tx: mpsc::Sender<Msg>,
+    dropped: Arc<AtomicUsize>,
+}
+
+impl<Msg> StreamBridge<Msg>
+    where Msg: Message
+{
+    fn new((tx, dropped): (mpsc::Sender<Msg>, Arc<AtomicUsize>)) -> Self {
+        StreamBridge {
+            tx,
+            dropped
+        }
+    }
+}
+
+impl<Msg> Actor for StreamBridge<Msg>
+    where Msg: Message
+{
+    type Msg = Msg;
+
+    fn recv(&mut self,
+            _: &Context<Self::Msg>,
+            msg: Self::Msg,
+            _: Sender) {
+        if let Err(e) = self.tx.try_send(msg) {
+            // a disconnected stream is being dropped and stops the bridge
+            if e.is_full() {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
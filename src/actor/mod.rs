@@ -1,10 +1,13 @@
 pub(crate) mod actor;
 pub(crate) mod actor_cell;
 pub(crate) mod actor_ref;
+pub(crate) mod actor_sink;
 pub(crate) mod channel;
+pub(crate) mod channel_stream;
 pub(crate) mod macros;
 pub(crate) mod props;
 pub(crate) mod selection;
+pub(crate) mod subscriptions;
 pub(crate) mod uri;
 
 use std::{
@@ -22,14 +25,18 @@ pub use self::{
         ActorRefFactory, TmpActorRefFactory, Tell, BoxedTell, Sender
     },
     actor_cell::Context,
+    actor_sink::ActorSink,
     channel::{
-        Channel, EventsChannel, Topic, All, SysTopic,
-        Publish, Subscribe, Unsubscribe, UnsubscribeAll,
-        ChannelMsg, ChannelRef, DLChannelMsg, DeadLetter, channel
+        Channel, ChannelConfig, ShardedChannel, ShardedChannelMsg, ShardedChannelRef,
+        EventsChannel, Topic, All, SysTopic,
+        Publish, Subscribe, SubscribeFiltered, Filter, Unsubscribe, UnsubscribeAll,
+        ChannelMsg, ChannelRef, DLChannelMsg, DeadLetter, DeadLetterReason,
+        channel, channel_with_config, sharded_channel
     },
+    channel_stream::ChannelStream,
     macros::actor,
     selection::{ActorSelection, ActorSelectionFactory},
-    uri::{ActorId, ActorUri, ActorPath},
+    uri::{ActorId, ActorUri, ActorPath, ActorAddress},
     props::{Props, BoxActorProd, ActorProducer, ActorArgs}
 };
 
@@ -41,19 +48,21 @@ pub type MsgResult<T> = Result<(), MsgError<T>>;
 #[derive(Clone)]
 pub struct MsgError<T> {
     pub msg: T,
+    pub reason: TellError,
 }
 
 impl<T> MsgError<T> {
-    pub fn new(msg: T) -> Self {
+    pub fn new(msg: T, reason: TellError) -> Self {
         MsgError {
-            msg
+            msg,
+            reason
         }
     }
 }
 
 impl<T> Error for MsgError<T> {
     fn description(&self) -> &str {
-        "The actor does not exist. It may have been terminated"
+        self.reason.description()
     }
 }
 
@@ -69,6 +78,65 @@ impl<T> fmt::Debug for MsgError<T> {
     }
 }
 
+/// Error type when a message can't be delivered to an actor
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TellError {
+    /// The actor doesn't accept messages of this type
+    UnsupportedType,
+    /// The actor has been terminated
+    Terminated,
+    /// The actor's mailbox has reached its capacity
+    MailboxFull,
+}
+
+impl Error for TellError {
+    fn description(&self) -> &str {
+        match *self {
+            TellError::UnsupportedType => "Failed to send message. Cause: The actor does not support the message type",
+            TellError::Terminated => "Failed to send message. Cause: The actor does not exist. It may have been terminated",
+            TellError::MailboxFull => "Failed to send message. Cause: The actor's mailbox is full"
+        }
+    }
+}
+
+impl fmt::Display for TellError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl fmt::Debug for TellError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Error type when `ActorRef::try_send` fails
+/// 
+/// The message that couldn't be sent is returned in `msg`.
+pub struct SendError<T> {
+    pub msg: T,
+    pub reason: TellError,
+}
+
+impl<T> Error for SendError<T> {
+    fn description(&self) -> &str {
+        self.reason.description()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
 /// Error type when an `try_tell` fails on `Option<ActorRef<Msg>>`
 pub struct TryMsgError<T> {
     pub msg: T,
@@ -1,11 +1,11 @@
-use std::iter::Peekable;
+use regex::Regex;
 
 use crate::{
     Message,
     system::SystemMsg,
     actor::{
-        BasicActorRef, Sender,
-        ActorReference
+        ActorRef, BasicActorRef, ActorReference, Sender, Tell,
+        DeadLetter, DeadLetterReason, DLChannelMsg, Publish
     },
     validate::{InvalidPath, validate_path}
 };
@@ -28,41 +28,48 @@ use crate::{
 /// part of the actor heirarchy and cloning messages.
 /// 
 /// A selection is anchored to an `ActorRef` and the path is relative
-/// to that actor's path.
+/// to that actor's path. Absolute paths, i.e. starting with `/`, are
+/// anchored at the root actor and can select actors under any of the
+/// `/user`, `/system` and `/temp` guardians.
 /// 
+/// Each segment of the path is one of:
+///
+/// - `..` the parent actor
+/// - `*` all children
+/// - `**` all descendants at any depth, including the actor itself
+/// - a glob matching child names, e.g. `worker-*`, `conn-?`, `shard-[0-3]`
+///   or `shard-[!0]`
+/// - an exact child name
+///
+/// For example `/user/**/conn-*` selects every actor named `conn-...`
+/// anywhere under `/user`.
+///
 /// `selection.try_tell()` is used to message actors in the selection.
 /// Since a selection is a collection of `BasicActorRef`s messaging is
 /// un-typed. Messages not supported by any actor in the selection will
-/// be dropped.
+/// be dropped. Messages sent to a selection that matches no actors are
+/// published to dead letters, with the selection's anchor as recipient.
 #[derive(Debug)]
 pub struct ActorSelection {
     anchor: BasicActorRef,
-    // dl: BasicActorRef,
+    dl: ActorRef<DLChannelMsg>,
     path_vec: Vec<Selection>,
-    path: String,
 }
 
 impl ActorSelection {
     pub fn new(anchor: BasicActorRef,
-                // dl: &BasicActorRef,
+                dl: &ActorRef<DLChannelMsg>,
                 path: String) -> Result<ActorSelection, InvalidPath> {
         validate_path(&path)?;
 
-        let path_vec: Vec<Selection> = path.split_terminator('/').map({|seg|
-            match seg {
-                ".." => Selection::SelectParent,
-                "*" => Selection::SelectAllChildren,
-                name => {
-                    Selection::SelectChildName(name.to_string())
-                }
-            }
-        }).collect();
+        let path_vec = path.split_terminator('/')
+            .map(|seg| Selection::from_segment(seg, &path))
+            .collect::<Result<Vec<Selection>, InvalidPath>>()?;
 
         Ok(ActorSelection {
             anchor,
-            // dl: dl.clone(),
-            path_vec,
-            path
+            dl: dl.clone(),
+            path_vec
         })
     }
 
@@ -71,121 +78,261 @@ impl ActorSelection {
                 sender: impl Into<Option<BasicActorRef>>)
         where Msg: Message
     {
-        fn walk<'a, I, Msg>(anchor: &BasicActorRef,
-                            // dl: &BasicActorRef,
-                            mut path_vec: Peekable<I>,
-                            msg: Msg,
-                            sender: &Sender,
-                            path: &String)
-            where I: Iterator<Item=&'a Selection>, Msg: Message
-        {
-            let seg = path_vec.next();
-
-            match seg {
-                Some(&Selection::SelectParent) => {
-                    if path_vec.peek().is_none() {
-                        let parent = anchor.parent();
-                        let _ = parent.try_tell(msg, sender.clone());
-                    } else {
-                        walk(&anchor.parent(), path_vec, msg, sender, path);
-                    }
-                },
-                Some(&Selection::SelectAllChildren) => {
-                    for child in anchor.children() {
-                        let _ = child.try_tell(msg.clone(), sender.clone());
-                    }
-                },
-                Some(&Selection::SelectChildName(ref name)) => {
-                    let child = anchor.children().filter({|c| c.name() == name}).last();
-                    if path_vec.peek().is_none() && child.is_some() {
-                        let _ = child.unwrap()
-                            .try_tell(msg, sender.clone());
-                    } else if path_vec.peek().is_some() && child.is_some() {
-                        walk(&child.as_ref().unwrap(),
-                            // dl,
-                            path_vec,
-                            msg,
-                            sender,
-                            path);
-                    } else {
-                        // todo send to deadletters?
-                    }
-                },
-                None => {}
-            }
+        let sender = sender.into();
+        let actors = self.resolve();
+
+        if actors.is_empty() {
+            self.dead_letter(msg, sender);
+            return;
         }
 
-        walk(&self.anchor,
-            // &self.dl,
-            self.path_vec.iter().peekable(),
-            msg,
-            &sender.into(),
-            &self.path);
+        for actor in actors {
+            let _ = actor.try_tell_supported(msg.clone(), sender.clone());
+        }
     }
 
     pub fn sys_tell(&self,
                 msg: SystemMsg,
                 sender: impl Into<Option<BasicActorRef>>) {
-        fn walk<'a, I>(anchor: &BasicActorRef,
-                            // dl: &BasicActorRef,
-                            mut path_vec: Peekable<I>,
-                            msg: SystemMsg,
-                            sender: &Sender,
-                            path: &String)
-            where I: Iterator<Item=&'a Selection>
-        {
-            let seg = path_vec.next();
-
-            match seg {
-                Some(&Selection::SelectParent) => {
-                    if path_vec.peek().is_none() {
-                        let parent = anchor.parent();
-                        parent.sys_tell(msg);
-                    } else {
-                        walk(&anchor.parent(), path_vec, msg, sender, path);
-                    }
-                },
-                Some(&Selection::SelectAllChildren) => {
-                    for child in anchor.children() {
-                        child.sys_tell(msg.clone());
-                    }
-                },
-                Some(&Selection::SelectChildName(ref name)) => {
-                    let child = anchor.children().filter({|c| c.name() == name}).last();
-                    if path_vec.peek().is_none() && child.is_some() {
-                        child.unwrap()
-                            .sys_tell(msg);
-                    } else if path_vec.peek().is_some() && child.is_some() {
-                        walk(&child.as_ref().unwrap(),
-                            // dl,
-                            path_vec,
-                            msg,
-                            sender,
-                            path);
-                    } else {
-                        // todo send to deadletters?
+        let actors = self.resolve();
+
+        if actors.is_empty() {
+            self.dead_letter(msg, sender.into());
+            return;
+        }
+
+        for actor in actors {
+            actor.sys_tell(msg.clone());
+        }
+    }
+
+    fn dead_letter<Msg: Message>(&self, msg: Msg, sender: Sender) {
+        let dl = DeadLetter::new(msg,
+                                DeadLetterReason::NoSelectionMatch,
+                                sender,
+                                self.anchor.clone());
+
+        self.dl.tell(Publish { topic: "dead_letter".into(), msg: dl }, None);
+    }
+
+    /// Returns the actors currently in the selection
+    ///
+    /// The selection is resolved each time this is called, so actors
+    /// created or terminated since the last call are reflected.
+    pub fn resolve(&self) -> Vec<BasicActorRef> {
+        // exact paths are found using the system's actor registry
+        if let Some(path) = self.exact_path() {
+            return self.anchor
+                .cell
+                .system()
+                .lookup(&path)
+                .into_iter()
+                .collect();
+        }
+
+        let mut actors = Vec::new();
+        walk(&self.anchor, &self.path_vec, &mut actors);
+        actors
+    }
+
+    /// Returns true if the actor at `path` is in the selection
+    ///
+    /// The path is matched against the selection's segments without
+    /// traversing the actor hierarchy, so this is cheap enough to check
+    /// for every actor that is created. Whether the actor exists isn't
+    /// checked. A glob or wildcard followed by `..` only matches if it
+    /// matches an ancestor of `path`.
+    pub fn matches(&self, path: &str) -> bool {
+        let target: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let anchor = self.anchor.path().to_string();
+        let pos: Vec<&str> = anchor.split('/').filter(|s| !s.is_empty()).collect();
+
+        match_path(pos, &self.path_vec, &target)
+    }
+
+    // The absolute path selected, if the selection only contains child names
+    fn exact_path(&self) -> Option<String> {
+        let mut path = self.anchor.path().to_string();
+
+        for seg in self.path_vec.iter() {
+            match *seg {
+                Selection::SelectChildName(ref name) => {
+                    if !path.ends_with('/') {
+                        path.push('/');
                     }
-                },
-                None => {}
+                    path.push_str(name);
+                }
+                _ => return None
             }
         }
 
-        walk(&self.anchor,
-            // &self.dl,
-            self.path_vec.iter().peekable(),
-            msg,
-            &sender.into(),
-            &self.path);
+        Some(path)
+    }
+}
+
+fn walk(anchor: &BasicActorRef,
+        path_vec: &[Selection],
+        actors: &mut Vec<BasicActorRef>) {
+
+    let (seg, rest) = match path_vec.split_first() {
+        Some(split) => split,
+        None => {
+            if !actors.iter().any(|a| a.path() == anchor.path()) {
+                actors.push(anchor.clone());
+            }
+            return;
+        }
+    };
+
+    match *seg {
+        Selection::SelectParent => {
+            walk(&anchor.parent(), rest, actors);
+        }
+        Selection::SelectAllChildren => {
+            for child in anchor.children() {
+                walk(&child, rest, actors);
+            }
+        }
+        Selection::SelectChildName(ref name) => {
+            if let Some(child) = anchor.children().find(|c| c.name() == name) {
+                walk(&child, rest, actors);
+            }
+        }
+        Selection::SelectChildPattern(ref pattern) => {
+            for child in anchor.children().filter(|c| pattern.is_match(c.name())) {
+                walk(&child, rest, actors);
+            }
+        }
+        Selection::SelectDescendants => {
+            // `**` matches zero or more levels
+            walk(anchor, rest, actors);
+            for child in anchor.children() {
+                walk(&child, path_vec, actors);
+            }
+        }
     }
+}
+
+// Matches the segments of an absolute path, `target`, against a selection
+// starting at `pos`, the segments of the current actor's path
+fn match_path<'a>(mut pos: Vec<&'a str>,
+                path_vec: &'a [Selection],
+                target: &[&'a str]) -> bool {
+
+    let (seg, rest) = match path_vec.split_first() {
+        Some(split) => split,
+        None => return pos == target
+    };
+
+    // the child of `pos` on the way to `target`, if `pos` is an ancestor
+    let next = if target.len() > pos.len() && target.starts_with(&pos) {
+        Some(target[pos.len()])
+    } else {
+        None
+    };
 
+    match *seg {
+        Selection::SelectParent => {
+            pos.pop();
+            match_path(pos, rest, target)
+        }
+        Selection::SelectChildName(ref name) => {
+            pos.push(name);
+            match_path(pos, rest, target)
+        }
+        Selection::SelectAllChildren => {
+            match next {
+                Some(child) => {
+                    pos.push(child);
+                    match_path(pos, rest, target)
+                }
+                None => false
+            }
+        }
+        Selection::SelectChildPattern(ref pattern) => {
+            match next {
+                Some(child) if pattern.is_match(child) => {
+                    pos.push(child);
+                    match_path(pos, rest, target)
+                }
+                _ => false
+            }
+        }
+        Selection::SelectDescendants => {
+            // `**` matches zero or more levels
+            if match_path(pos.clone(), rest, target) {
+                return true;
+            }
 
+            match next {
+                Some(child) => {
+                    pos.push(child);
+                    match_path(pos, path_vec, target)
+                }
+                None => false
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
 enum Selection {
     SelectParent,
     SelectChildName(String),
+    SelectChildPattern(Regex),
     SelectAllChildren,
+    SelectDescendants,
+}
+
+impl Selection {
+    fn from_segment(seg: &str, path: &str) -> Result<Selection, InvalidPath> {
+        let sel = match seg {
+            ".." => Selection::SelectParent,
+            "*" => Selection::SelectAllChildren,
+            "**" => Selection::SelectDescendants,
+            name if is_glob(name) => {
+                let pattern = Regex::new(&glob_to_regex(name))
+                    .map_err(|_| InvalidPath::new(path))?;
+                Selection::SelectChildPattern(pattern)
+            }
+            name => Selection::SelectChildName(name.to_string())
+        };
+
+        Ok(sel)
+    }
+}
+
+fn is_glob(seg: &str) -> bool {
+    seg.contains(|c| c == '*' || c == '?' || c == '[')
+}
+
+// Converts a glob segment to a regex matching an actor name:
+// `*` any characters, `?` a single character,
+// `[abc]`, `[a-z]` a character class and `[!abc]` a negated class
+pub(crate) fn glob_to_regex(glob: &str) -> String {
+    let mut rgx = String::from("^");
+    let mut in_class = false;
+
+    for c in glob.chars() {
+        match c {
+            '*' if !in_class => rgx.push_str(".*"),
+            '?' if !in_class => rgx.push('.'),
+            '[' if !in_class => {
+                in_class = true;
+                rgx.push('[');
+            }
+            ']' if in_class => {
+                in_class = false;
+                rgx.push(']');
+            }
+            '!' if in_class && rgx.ends_with('[') => rgx.push('^'),
+            '-' if in_class => rgx.push('-'),
+            c => rgx.push_str(&regex::escape(&c.to_string()))
+        }
+    }
+
+    rgx.push('$');
+    rgx
 }
 
 pub trait ActorSelectionFactory {
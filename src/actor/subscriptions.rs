@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::actor::{ActorReference, BoxedTell, Topic, channel::Filter};
+
+const SEPARATOR: char = '.';
+const ANY_SEGMENT: &str = "*";
+const ANY_SEGMENTS: &str = "#";
+
+/// Channel subscriptions indexed by topic
+///
+/// Topics are hierarchical, with segments separated by `.`
+/// e.g. `orders.eu.created`. Subscriptions can use wildcards:
+///
+/// - `*` matches exactly one segment, e.g. `orders.*.created`
+/// - `#` matches zero or more segments, e.g. `orders.#`
+///
+/// A subscription to `*` alone (`All`) matches every topic.
+///
+/// Subscriptions are stored in a trie keyed by segment, so a publish
+/// only visits the branches that can match its topic rather than
+/// every subscription.
+pub(crate) struct Subscriptions<Msg> {
+    root: Node<Msg>,
+}
+
+struct Subscriber<Msg> {
+    actor: BoxedTell<Msg>,
+    filter: Option<Filter<Msg>>,
+}
+
+impl<Msg> Subscriber<Msg> {
+    fn accepts(&self, msg: &Msg) -> bool {
+        match self.filter {
+            Some(ref filter) => filter(msg),
+            None => true
+        }
+    }
+}
+
+struct Node<Msg> {
+    subs: Vec<Subscriber<Msg>>,
+    children: HashMap<String, Node<Msg>>,
+}
+
+impl<Msg> Node<Msg> {
+    fn new() -> Self {
+        Node {
+            subs: Vec::new(),
+            children: HashMap::new()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.subs.is_empty() && self.children.is_empty()
+    }
+
+    fn remove(&mut self, segs: &[&str], actor: &dyn ActorReference) {
+        match segs.split_first() {
+            Some((seg, rest)) => {
+                if let Some(child) = self.children.get_mut(*seg) {
+                    child.remove(rest, actor);
+                    if child.is_empty() {
+                        self.children.remove(*seg);
+                    }
+                }
+            }
+            None => {
+                if let Some(pos) = self.subs.iter().position(|s| s.actor.uri() == actor.uri()) {
+                    self.subs.remove(pos);
+                }
+            }
+        }
+    }
+
+    fn remove_all(&mut self, actor: &dyn ActorReference) {
+        self.subs.retain(|s| s.actor.uri() != actor.uri());
+
+        for child in self.children.values_mut() {
+            child.remove_all(actor);
+        }
+        self.children.retain(|_, child| !child.is_empty());
+    }
+
+    fn matches<'a>(&'a self, segs: &[&str], matched: &mut Vec<&'a Node<Msg>>) {
+        if let Some(any) = self.children.get(ANY_SEGMENTS) {
+            // '#' matches any number of the remaining segments
+            for i in 0..=segs.len() {
+                any.matches(&segs[i..], matched);
+            }
+        }
+
+        match segs.split_first() {
+            Some((seg, rest)) => {
+                if let Some(child) = self.children.get(*seg) {
+                    child.matches(rest, matched);
+                }
+
+                if let Some(child) = self.children.get(ANY_SEGMENT) {
+                    child.matches(rest, matched);
+                }
+            }
+            None => {
+                // a node can be reached more than once through '#'
+                if !matched.iter().any(|n| std::ptr::eq(*n, self)) {
+                    matched.push(self);
+                }
+            }
+        }
+    }
+}
+
+impl<Msg> Subscriptions<Msg> {
+    pub fn new() -> Self {
+        Subscriptions {
+            root: Node::new()
+        }
+    }
+
+    pub fn subscribe(&mut self,
+                    topic: &Topic,
+                    actor: BoxedTell<Msg>,
+                    filter: Option<Filter<Msg>>) {
+        let mut node = &mut self.root;
+
+        for seg in segments(topic) {
+            node = node.children
+                .entry(seg.to_string())
+                .or_insert_with(Node::new);
+        }
+
+        node.subs.push(Subscriber { actor, filter });
+    }
+
+    pub fn unsubscribe(&mut self, topic: &Topic, actor: &dyn ActorReference) {
+        self.root.remove(&segments(topic), actor);
+    }
+
+    pub fn unsubscribe_all(&mut self, actor: &dyn ActorReference) {
+        self.root.remove_all(actor);
+    }
+
+    /// Returns the subscribers of topics matching `topic` that accept `msg`
+    pub fn matches<'a>(&'a self,
+                        topic: &Topic,
+                        msg: &'a Msg)
+                        -> impl Iterator<Item = &'a BoxedTell<Msg>> {
+        let mut matched = Vec::new();
+        self.root.matches(&segments(topic), &mut matched);
+
+        matched.into_iter()
+            .flat_map(|node| node.subs.iter())
+            .filter(move |sub| sub.accepts(msg))
+            .map(|sub| &sub.actor)
+    }
+}
+
+/// Returns true if `topic` matches the subscription `pattern`
+pub(crate) fn topic_matches(pattern: &Topic, topic: &Topic) -> bool {
+    fn matches(pattern: &[&str], topic: &[&str]) -> bool {
+        match pattern.split_first() {
+            Some((&ANY_SEGMENTS, rest)) => {
+                (0..=topic.len()).any(|i| matches(rest, &topic[i..]))
+            }
+            Some((seg, rest)) => {
+                match topic.split_first() {
+                    Some((t, topic)) => (*seg == ANY_SEGMENT || seg == t) && matches(rest, topic),
+                    None => false
+                }
+            }
+            None => topic.is_empty()
+        }
+    }
+
+    matches(&segments(pattern), &topic.as_str().split(SEPARATOR).collect::<Vec<_>>())
+}
+
+fn segments(topic: &Topic) -> Vec<&str> {
+    // `All` matches every topic
+    if topic.as_str() == ANY_SEGMENT {
+        return vec![ANY_SEGMENTS];
+    }
+
+    topic.as_str().split(SEPARATOR).collect()
+}
@@ -1,9 +1,12 @@
 use std::{
     fmt,
     hash::{Hash, Hasher},
+    str::FromStr,
     sync::Arc
 };
 
+use crate::validate::{InvalidAddress, validate_name, validate_path};
+
 pub type ActorId = usize;
 pub struct ActorPath(Arc<String>);
 
@@ -52,7 +55,7 @@ impl Clone for ActorPath {
 }
 
 /// An `ActorUri` represents the location of an actor, including the
-/// path and actor system host.
+/// path, actor system name and host.
 /// 
 /// Note: `host` is currently unused but will be utilized when
 /// networking and clustering are introduced.
@@ -61,12 +64,28 @@ pub struct ActorUri {
     pub uid: ActorId,
     pub name: Arc<String>,
     pub path: ActorPath,
+    pub system: Arc<String>,
     pub host: Arc<String>,
 }
 
+impl ActorUri {
+    /// Returns the canonical address of this actor
+    pub fn address(&self) -> ActorAddress {
+        ActorAddress {
+            system: self.system.to_string(),
+            host: self.host.to_string(),
+            port: None,
+            path: self.path.to_string(),
+            uid: Some(self.uid)
+        }
+    }
+}
+
+// The uid identifies the incarnation of the actor at the path,
+// so a new actor created at the same path is a different actor
 impl PartialEq for ActorUri {
     fn eq(&self, other: &ActorUri) -> bool {
-        self.path == other.path
+        self.path == other.path && self.uid == other.uid
     }
 }
 
@@ -89,3 +108,110 @@ impl fmt::Debug for ActorUri {
         write!(f, "{}://{}#{}", self.host, self.path, self.uid)
     }
 }
+
+/// The address of an actor as text, in the form
+/// `riker://system@host[:port]/path[#uid]`
+///
+/// Addresses can be converted to and from strings using `Display` and
+/// `FromStr`, so actors can be referred to in config, logs and messages.
+/// A local address can be resolved to its actor using
+/// `ActorSystem::resolve_address`.
+///
+/// If `uid` is present the address refers to a specific incarnation of
+/// the actor at `path`.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// let addr: ActorAddress = "riker://my-app@localhost:8000/user/worker#101".parse().unwrap();
+///
+/// assert_eq!(addr.system, "my-app");
+/// assert_eq!(addr.host, "localhost");
+/// assert_eq!(addr.port, Some(8000));
+/// assert_eq!(addr.path, "/user/worker");
+/// assert_eq!(addr.uid, Some(101));
+///
+/// assert_eq!(addr.to_string(), "riker://my-app@localhost:8000/user/worker#101");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ActorAddress {
+    pub system: String,
+    pub host: String,
+    pub port: Option<u16>,
+    pub path: String,
+    pub uid: Option<ActorId>,
+}
+
+const SCHEME: &str = "riker://";
+
+impl fmt::Display for ActorAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}@{}", SCHEME, self.system, self.host)?;
+
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+
+        write!(f, "{}", self.path)?;
+
+        if let Some(uid) = self.uid {
+            write!(f, "#{}", uid)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ActorAddress {
+    type Err = InvalidAddress;
+
+    fn from_str(addr: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidAddress::new(addr);
+
+        if !addr.starts_with(SCHEME) {
+            return Err(invalid());
+        }
+        let addr_str = &addr[SCHEME.len()..];
+
+        let (authority, path) = match addr_str.find('/') {
+            Some(pos) => addr_str.split_at(pos),
+            None => return Err(invalid())
+        };
+
+        let (path, uid) = match path.find('#') {
+            Some(pos) => {
+                let uid = path[pos + 1..].parse::<ActorId>().map_err(|_| invalid())?;
+                (&path[..pos], Some(uid))
+            }
+            None => (path, None)
+        };
+
+        let (system, host) = match authority.find('@') {
+            Some(pos) => (&authority[..pos], &authority[pos + 1..]),
+            None => return Err(invalid())
+        };
+
+        let (host, port) = match host.rfind(':') {
+            Some(pos) => {
+                let port = host[pos + 1..].parse::<u16>().map_err(|_| invalid())?;
+                (&host[..pos], Some(port))
+            }
+            None => (host, None)
+        };
+
+        if host.is_empty() {
+            return Err(invalid());
+        }
+        validate_name(system).map_err(|_| invalid())?;
+        validate_path(path).map_err(|_| invalid())?;
+
+        Ok(ActorAddress {
+            system: system.to_string(),
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+            uid
+        })
+    }
+}
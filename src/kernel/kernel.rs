@@ -1,4 +1,5 @@
 use std::{
+    any::Any,
     panic::{catch_unwind, AssertUnwindSafe},
     sync::{Arc, Mutex}
 };
@@ -19,7 +20,10 @@ use crate::{
         kernel_ref::KernelRef,
         mailbox::{Mailbox, run_mailbox, flush_to_deadletters}
     },
-    system::{ActorSystem, SystemMsg, ActorTerminated, ActorRestarted}
+    system::{
+        ActorSystem, SystemMsg, ActorTerminated, ActorRestarted,
+        ActorFailed, ActorFailedToStart
+    }
 };
 
 pub struct Dock<A: Actor> {
@@ -50,7 +54,11 @@ pub fn kernel<A>(props: BoxActorProd<A>,
     let mut sys = sys.clone();
     let mut asys = sys.clone();
     let akr = kr.clone();
-    let actor = start_actor(&props)?;
+    let actor = start_actor(&props).map_err(|reason| {
+        let uri = cell.uri().clone();
+        sys.publish_event(ActorFailedToStart { uri, reason }.into());
+        CreateError::Panicked
+    })?;
     let cell = cell.init(&kr);
 
     let dock = Dock {
@@ -73,11 +81,19 @@ pub fn kernel<A>(props: BoxActorProd<A>,
                     let mb = mailbox.clone();
                     let d = dock.clone();
 
-                    let _ = std::panic::catch_unwind(
+                    let res = std::panic::catch_unwind(
                         AssertUnwindSafe(||{
                             run_mailbox(mb, ctx, d)
                         })
-                    );//.unwrap();
+                    );
+
+                    if let Err(e) = res {
+                        let failed = ActorFailed {
+                            actor: actor_ref.clone().into(),
+                            reason: panic_reason(&e)
+                        };
+                        asys.publish_event(failed.into());
+                    }
                 }
                 KernelMsg::RestartActor => {
                     restart_actor(&dock, actor_ref.clone().into(), &props, &asys);
@@ -111,8 +127,10 @@ fn restart_actor<A>(dock: &Dock<A>,
             actor_ref.sys_tell(SystemMsg::ActorInit);
             sys.publish_event(ActorRestarted { actor: actor_ref }.into());
         }
-        Err(_) => {
+        Err(reason) => {
             warn!("Actor failed to restart: {:?}", actor_ref);
+            let uri = actor_ref.uri().clone();
+            sys.publish_event(ActorFailedToStart { uri, reason }.into());
         }
     }
 }
@@ -122,6 +140,7 @@ fn terminate_actor<Msg>(mbox: &Mailbox<Msg>,
                     sys: &ActorSystem)
     where Msg: Message
 {
+    actor_ref.cell.set_terminated();
     sys.provider.unregister(actor_ref.path());
     flush_to_deadletters(mbox, &actor_ref, sys);
     sys.publish_event(ActorTerminated { actor: actor_ref.clone() }.into());
@@ -132,12 +151,19 @@ fn terminate_actor<Msg>(mbox: &Mailbox<Msg>,
     }
 }
 
-fn start_actor<A>(props: &BoxActorProd<A>) -> Result<A, CreateError>
+/// Produces the actor, returning the panic message if it panics
+fn start_actor<A>(props: &BoxActorProd<A>) -> Result<A, String>
     where A: Actor
 {
-    let actor = catch_unwind(|| props.produce()).map_err(|_| {
-            CreateError::Panicked
-    })?;
+    catch_unwind(|| props.produce()).map_err(|e| panic_reason(&e))
+}
 
-    Ok(actor)
+fn panic_reason(payload: &Box<dyn Any + Send>) -> String {
+    if let Some(reason) = payload.downcast_ref::<&str>() {
+        reason.to_string()
+    } else if let Some(reason) = payload.downcast_ref::<String>() {
+        reason.clone()
+    } else {
+        "unknown panic".to_string()
+    }
 }
@@ -8,7 +8,7 @@ use futures::{
 
 use crate::{
     Message, Envelope, AnyMessage,
-    actor::{MsgError, MsgResult},
+    actor::{MsgError, MsgResult, TellError},
     kernel::{
         KernelMsg,
         mailbox::{MailboxSender, MailboxSchedule, AnySender}
@@ -65,7 +65,7 @@ pub fn dispatch<Msg>(msg: Envelope<Msg>,
             
             Ok(())
         }
-        Err(e) => Err(MsgError::new(e.msg.into()))
+        Err(e) => Err(MsgError::new(e.msg.into(), e.reason))
     }
 }
 
@@ -74,7 +74,7 @@ pub fn dispatch_any(msg: &mut AnyMessage,
                     mbox: &Arc<dyn AnySender>,
                     kernel: &KernelRef,
                     sys: &ActorSystem)
-                    -> Result<(), ()> {
+                    -> Result<(), TellError> {
 
     match mbox.try_any_enqueue(msg, sender) {
         Ok(_) => {
@@ -85,7 +85,7 @@ pub fn dispatch_any(msg: &mut AnyMessage,
             
             Ok(())
         }
-        Err(_) => Err(())
+        Err(e) => Err(e)
     }
 }
 
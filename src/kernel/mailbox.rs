@@ -1,9 +1,11 @@
 use std::thread;
 use std::{
+    any::Any,
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering}
     },
+    task::{Context as TaskContext, Poll}
 };
 
 use config::Config;
@@ -16,7 +18,7 @@ use crate::{
     },
     actor::*,
     actor::actor_cell::ExtendedCell,
-    system::ActorCreated,
+    system::{ActorCreated, SupervisorDecision},
     kernel::{
         kernel::Dock,
         queue::{
@@ -35,11 +37,15 @@ pub trait MailboxSchedule {
 pub trait AnySender : Send + Sync {
     fn try_any_enqueue(&self, msg: &mut AnyMessage,
                         sender: Sender)
-                        -> Result<(), ()>;
+                        -> Result<(), TellError>;
 
     fn set_sched(&self, b: bool);
 
     fn is_sched(&self) -> bool;
+
+    fn msg_count(&self) -> usize;
+
+    fn as_any(&self) -> &dyn Any;
 }
 
 #[derive(Clone)]
@@ -54,6 +60,16 @@ impl<Msg> MailboxSender<Msg>
     pub fn try_enqueue(&self, msg: Envelope<Msg>) -> EnqueueResult<Msg> {
         self.queue.try_enqueue(msg)
     }
+
+    /// Number of messages waiting in the mailbox
+    pub fn msg_count(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `Ready` if the mailbox has space for a message
+    pub fn poll_ready(&self, cx: &mut TaskContext<'_>) -> Poll<()> {
+        self.queue.poll_ready(cx)
+    }
 }
 
 impl<Msg> MailboxSchedule for MailboxSender<Msg>
@@ -72,14 +88,20 @@ impl<Msg> AnySender for MailboxSender<Msg>
     where Msg: Message
 {
     fn try_any_enqueue(&self, msg: &mut AnyMessage, sender: Sender)
-                        -> Result<(), ()> {
+                        -> Result<(), TellError> {
         
-        let actual = msg.take()?;
-        let msg = Envelope {
+        let actual = msg.take().map_err(|_| TellError::UnsupportedType)?;
+        let envelope = Envelope {
             msg: actual,
             sender
         };
-        self.try_enqueue(msg).map_err(|_| ())
+        self.try_enqueue(envelope).map_err(|e| {
+            // return a one-time message so the caller can handle it
+            if msg.one_time {
+                msg.msg = Some(Box::new(e.msg.msg));
+            }
+            e.reason
+        })
     }
 
     fn set_sched(&self, b: bool) {
@@ -89,6 +111,14 @@ impl<Msg> AnySender for MailboxSender<Msg>
     fn is_sched(&self) -> bool {
         self.is_scheduled()
     }
+
+    fn msg_count(&self) -> usize {
+        self.msg_count()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 unsafe impl<Msg: Message> Send for MailboxSender<Msg> {}
@@ -101,7 +131,8 @@ pub struct Mailbox<Msg: Message> {
 
 pub struct MailboxInner<Msg: Message> {
     msg_process_limit: u32,
-    queue: QueueReader<Msg>,
+    queue: Arc<QueueReader<Msg>>,
+    shared: bool,
     sys_queue: QueueReader<SystemMsg>,
     suspended: Arc<AtomicBool>,
     scheduled: Arc<AtomicBool>,
@@ -154,13 +185,23 @@ impl<Msg> MailboxSchedule for Mailbox<Msg>
     }
 }
 
-pub fn mailbox<Msg>(msg_process_limit: u32)
-                    -> (MailboxSender<Msg>, MailboxSender<SystemMsg>, Mailbox<Msg>)
+pub type MailboxParts<Msg> = (MailboxSender<Msg>, MailboxSender<SystemMsg>, Mailbox<Msg>);
+
+pub fn mailbox<Msg>(msg_process_limit: u32, capacity: Option<usize>) -> MailboxParts<Msg>
     where Msg: Message
 {
-    
-    let (qw, qr) = queue::<Msg>();
-    let (sqw, sqr) = queue::<SystemMsg>();
+    let (qw, qr) = queue::<Msg>(capacity);
+    mailbox_with_queue(msg_process_limit, qw, Arc::new(qr), false)
+}
+
+fn mailbox_with_queue<Msg>(msg_process_limit: u32,
+                            qw: QueueWriter<Msg>,
+                            qr: Arc<QueueReader<Msg>>,
+                            shared: bool)
+                            -> MailboxParts<Msg>
+    where Msg: Message
+{
+    let (sqw, sqr) = queue::<SystemMsg>(None);
 
     let scheduled = Arc::new(AtomicBool::new(false));
 
@@ -177,6 +218,7 @@ pub fn mailbox<Msg>(msg_process_limit: u32)
     let mailbox = MailboxInner {
         msg_process_limit,
         queue: qr,
+        shared,
         sys_queue: sqr,
         suspended: Arc::new(AtomicBool::new(true)),
         scheduled
@@ -189,6 +231,42 @@ pub fn mailbox<Msg>(msg_process_limit: u32)
     (sender, sys_sender, mailbox)
 }
 
+/// A message queue shared by the mailboxes of several actors
+///
+/// Each actor created with a mailbox from `SharedQueue::mailbox` has its
+/// own system message queue, but messages are dequeued from the shared
+/// queue by whichever of those actors runs next.
+pub struct SharedQueue<Msg: Message> {
+    writer: QueueWriter<Msg>,
+    reader: Arc<QueueReader<Msg>>,
+}
+
+impl<Msg: Message> SharedQueue<Msg> {
+    pub fn new(capacity: Option<usize>) -> Self {
+        let (writer, reader) = queue::<Msg>(capacity);
+
+        SharedQueue {
+            writer,
+            reader: Arc::new(reader)
+        }
+    }
+
+    pub fn mailbox(&self, msg_process_limit: u32) -> MailboxParts<Msg> {
+        mailbox_with_queue(msg_process_limit,
+                            self.writer.clone(),
+                            self.reader.clone(),
+                            true)
+    }
+
+    /// Messages remaining in a shared queue are not flushed when one of
+    /// the actors sharing it terminates, since they can still be processed
+    /// by the other actors. This should be used once all actors sharing
+    /// the queue have terminated.
+    pub fn flush_to_deadletters(&self, actor: &BasicActorRef, sys: &ActorSystem) {
+        flush_queue(&self.reader, actor, sys);
+    }
+}
+
 pub fn run_mailbox<A>(mbox: Mailbox<A::Msg>,
                         ctx: Context<A::Msg>,
                         mut dock: Dock<A>)
@@ -281,7 +359,7 @@ fn process_sys_msgs<A>(mbox: &Mailbox<A::Msg>,
             SystemMsg::ActorInit => handle_init(mbox, ctx, cell, actor),
             SystemMsg::Command(cmd) => cell.receive_cmd(cmd, actor),
             SystemMsg::Event(evt) => handle_evt(evt, ctx, cell, actor),
-            SystemMsg::Failed(failed) => handle_failed(failed, cell, actor)
+            SystemMsg::Failed(failed) => handle_failed(failed, ctx, cell, actor)
         }
     }
 }
@@ -308,11 +386,21 @@ fn handle_init<A>(mbox: &Mailbox<A::Msg>,
 }
 
 fn handle_failed<A>(failed: BasicActorRef,
+                    ctx: &Context<A::Msg>,
                     cell: &ExtendedCell<A::Msg>,
                     actor: &mut Option<A>)
     where A: Actor
 {
-    cell.handle_failure(failed, actor.as_mut().unwrap().supervisor_strategy())
+    let strategy = actor.as_mut().unwrap().supervisor_strategy();
+
+    let decision = SupervisorDecision {
+        supervisor: cell.myself().into(),
+        actor: failed.clone(),
+        strategy: strategy.clone()
+    };
+    ctx.system.publish_event(decision.into());
+
+    cell.handle_failure(failed, strategy)
 }
 
 fn handle_evt<A>(evt: SystemEvent,
@@ -360,17 +448,26 @@ pub fn flush_to_deadletters<Msg>(mbox: &Mailbox<Msg>,
                                 actor: &BasicActorRef,
                                 sys: &ActorSystem)
     where Msg: Message
+{
+    if !mbox.inner.shared {
+        flush_queue(&mbox.inner.queue, actor, sys);
+    }
+}
+
+fn flush_queue<Msg>(queue: &QueueReader<Msg>,
+                    actor: &BasicActorRef,
+                    sys: &ActorSystem)
+    where Msg: Message
 {
     loop {
-        match mbox.try_dequeue() {
+        match queue.try_dequeue() {
             Ok(msg) => {
                 match (msg.msg, msg.sender) {
                     (msg, sender) => {
-                        let dl = DeadLetter {
-                            msg: format!("{:?}", msg),
-                            sender: sender,
-                            recipient: actor.clone()
-                        };
+                        let dl = DeadLetter::new(msg,
+                                                DeadLetterReason::Terminated,
+                                                sender,
+                                                actor.clone());
 
                         sys.dead_letters()
                             .tell(Publish { topic: "dead_letter".into(), msg: dl }, None);
@@ -1,6 +1,6 @@
 use std::{
     sync::{Arc, Mutex},
-    collections::HashSet,
+    collections::HashMap,
 };
 use log::trace;
 
@@ -9,7 +9,7 @@ use crate::{
     actor::actor_cell::{ActorCell, ExtendedCell},
     kernel::{
         kernel::kernel,
-        mailbox::mailbox
+        mailbox::{mailbox, MailboxParts}
     },
     system::{
         ActorSystem, SystemMsg,
@@ -24,14 +24,16 @@ pub struct Provider {
 }
 
 struct ProviderInner {
-    paths: HashSet<ActorPath>,
+    // registered paths are reserved while the actor is being created
+    // and map to the actor once it has been created
+    paths: HashMap<ActorPath, Option<BasicActorRef>>,
     counter: ActorId,
 }
 
 impl Provider {
     pub fn new() -> Self {
         let inner = ProviderInner {
-                paths: HashSet::new(),
+                paths: HashMap::new(),
                 counter: 100 // ActorIds start at 100
         };
 
@@ -46,6 +48,19 @@ impl Provider {
                         parent: &BasicActorRef,
                         sys: &ActorSystem) -> Result<ActorRef<A::Msg>, CreateError>
         where A: Actor + 'static
+    {
+        let capacity = mailbox_capacity(parent, sys);
+        let mailbox = mailbox::<A::Msg>(sys.sys_settings().msg_process_limit, capacity);
+        self.create_actor_with_mailbox(props, name, parent, sys, mailbox)
+    }
+
+    pub(crate) fn create_actor_with_mailbox<A>(&self,
+                        props: BoxActorProd<A>,
+                        name: &str,
+                        parent: &BasicActorRef,
+                        sys: &ActorSystem,
+                        mailbox: MailboxParts<A::Msg>) -> Result<ActorRef<A::Msg>, CreateError>
+        where A: Actor + 'static
     {
         validate_name(name)?;
         
@@ -58,12 +73,11 @@ impl Provider {
             uid,
             path,
             name: Arc::new(name.into()),
+            system: Arc::new(sys.name()),
             host: sys.host()
         };
 
-        let (sender, sys_sender, mb) =
-                                    mailbox::<A::Msg>(sys.sys_settings()
-                                    .msg_process_limit);
+        let (sender, sys_sender, mb) = mailbox;
 
         let cell = ExtendedCell::new(uri.uid,
                                     uri.clone(),
@@ -79,6 +93,7 @@ impl Provider {
 
         let actor = ActorRef::new(cell);
         let child = BasicActorRef::from(actor.clone());
+        self.add_actor(&child);
         parent.cell.add_child(child);
         actor.sys_tell(SystemMsg::ActorInit);
 
@@ -88,11 +103,11 @@ impl Provider {
     fn register(&self, path: &ActorPath) -> Result<ActorId, CreateError> {
         match self.inner.lock() {
             Ok(mut inner) => {
-                if inner.paths.contains(path) {
+                if inner.paths.contains_key(path) {
                     return Err(CreateError::AlreadyExists(path.clone()));
                 }
 
-                inner.paths.insert(path.clone());
+                inner.paths.insert(path.clone(), None);
                 let id = inner.counter;
                 inner.counter += 1;
 
@@ -104,21 +119,38 @@ impl Provider {
         }
     }
 
+    fn add_actor(&self, actor: &BasicActorRef) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.paths.insert(actor.path().clone(), Some(actor.clone()));
+    }
+
     pub fn unregister(&self, path: &ActorPath) {
         let mut inner = self.inner.lock().unwrap();
         inner.paths.remove(path);
     }
+
+    /// Returns the actor at `path`, if it exists
+    pub fn lookup(&self, path: &ActorPath) -> Option<BasicActorRef> {
+        let inner = self.inner.lock().unwrap();
+        inner.paths.get(path).cloned().and_then(|actor| actor)
+    }
 }
 
 pub fn create_root(sys: &ActorSystem) -> SysActors {
     let root = root(sys);
 
-    SysActors {
+    let sys_actors = SysActors {
         root: root.clone(),
         user: guardian(1, "user", "/user", &root, sys),
         sysm: guardian(2, "system", "/system", &root, sys),
         temp: guardian(3, "temp", "/temp", &root, sys)
+    };
+
+    for actor in &[&sys_actors.root, &sys_actors.user, &sys_actors.sysm, &sys_actors.temp] {
+        sys.provider.add_actor(actor);
     }
+
+    sys_actors
 }
 
 fn root(sys: &ActorSystem) -> BasicActorRef {
@@ -126,9 +158,10 @@ fn root(sys: &ActorSystem) -> BasicActorRef {
         uid: 0,
         name: Arc::new("root".to_string()),
         path: ActorPath::new("/"),
-        host: Arc::new("localhost".to_string())
+        system: Arc::new(sys.name()),
+        host: sys.host()
     };
-    let (sender, sys_sender, _mb) = mailbox::<SystemMsg>(100);
+    let (sender, sys_sender, _mb) = mailbox::<SystemMsg>(100, None);
 
     // Big bang: all actors have a parent.
     // This means root also needs a parent.
@@ -152,7 +185,7 @@ fn root(sys: &ActorSystem) -> BasicActorRef {
 
     // root
     let props: BoxActorProd<Guardian> = Props::new_args(Guardian::new, "root".to_string());
-    let (sender, sys_sender, mb) = mailbox::<SystemMsg>(100);
+    let (sender, sys_sender, mb) = mailbox::<SystemMsg>(100, None);
 
     let cell = ExtendedCell::new(uri.uid,
                                 uri.clone(),
@@ -180,11 +213,12 @@ fn guardian(uid: ActorId,
         uid,
         name: Arc::new(name.to_string()),
         path: ActorPath::new(path),
-        host: Arc::new("localhost".to_string())
+        system: Arc::new(sys.name()),
+        host: sys.host()
     };
 
     let props: BoxActorProd<Guardian> = Props::new_args(Guardian::new, name.to_string());
-    let (sender, sys_sender, mb) = mailbox::<SystemMsg>(100);
+    let (sender, sys_sender, mb) = mailbox::<SystemMsg>(100, None);
 
     let cell = ExtendedCell::new(uri.uid,
                                 uri.clone(),
@@ -227,3 +261,15 @@ impl Actor for Guardian {
         trace!("{} guardian stopped", self.name);
     }
 }
+
+/// The mailbox capacity of an actor created under `parent`
+///
+/// System actors, such as the logger and the system channels,
+/// must not reject messages so only user actors are bounded.
+pub(crate) fn mailbox_capacity(parent: &BasicActorRef, sys: &ActorSystem) -> Option<usize> {
+    if parent.path().to_string().starts_with("/user") {
+        sys.sys_settings().mailbox_capacity
+    } else {
+        None
+    }
+}
@@ -1,15 +1,25 @@
-use std::sync::{
-    Mutex,
-    mpsc::{channel, Sender, Receiver}
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{channel, Sender, Receiver}
+    },
+    task::{Context, Poll, Waker}
 };
 
-use crate::{Message, Envelope};
+use crate::{Message, Envelope, actor::TellError};
 
-pub fn queue<Msg: Message>() -> (QueueWriter<Msg>, QueueReader<Msg>) {
+/// Creates a queue, bounded to `capacity` messages if given
+pub fn queue<Msg: Message>(capacity: Option<usize>) -> (QueueWriter<Msg>, QueueReader<Msg>) {
     let (tx, rx) = channel::<Envelope<Msg>>();
+    let len = Arc::new(AtomicUsize::new(0));
+    let waiting = Arc::new(Waiting::default());
    
     let qw = QueueWriter {
         tx: tx,
+        len: len.clone(),
+        capacity,
+        waiting: waiting.clone(),
     };
 
     let qr = QueueReaderInner {
@@ -18,27 +28,105 @@ pub fn queue<Msg: Message>() -> (QueueWriter<Msg>, QueueReader<Msg>) {
     };
 
     let qr = QueueReader {
-        inner: Mutex::new(qr)
+        inner: Mutex::new(qr),
+        len,
+        waiting
     };
 
     (qw, qr)
 }
 
+/// Tasks waiting for space in a bounded queue
+#[derive(Default)]
+struct Waiting {
+    any: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl Waiting {
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.wakers.lock().unwrap();
+
+        // a task polled again replaces its waker rather than adding another
+        match wakers.iter_mut().find(|w| w.will_wake(waker)) {
+            Some(w) => w.clone_from(waker),
+            None => wakers.push(waker.clone())
+        }
+        self.any.store(true, Ordering::SeqCst);
+    }
+
+    fn wake(&self) {
+        // avoid taking the lock on every dequeue
+        if self.any.swap(false, Ordering::SeqCst) {
+            for waker in self.wakers.lock().unwrap().drain(..) {
+                waker.wake();
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct QueueWriter<Msg: Message> {
     tx: Sender<Envelope<Msg>>,
+    len: Arc<AtomicUsize>,
+    capacity: Option<usize>,
+    waiting: Arc<Waiting>,
 }
 
 impl<Msg: Message> QueueWriter<Msg> {
     pub fn try_enqueue(&self, msg: Envelope<Msg>) -> EnqueueResult<Msg> {
+        // count the message before sending so that a concurrent
+        // dequeue never observes a length below zero
+        let len = self.len.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(capacity) = self.capacity {
+            if len >= capacity {
+                self.len.fetch_sub(1, Ordering::Relaxed);
+                return Err(EnqueueError { msg, reason: TellError::MailboxFull });
+            }
+        }
+
         self.tx.send(msg)
             .map(|_| ())
-            .map_err(|e| EnqueueError { msg: e.0 })
+            .map_err(|e| {
+                self.len.fetch_sub(1, Ordering::Relaxed);
+                EnqueueError { msg: e.0, reason: TellError::Terminated }
+            })
+    }
+
+    /// Number of messages in the queue
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns `Ready` if the queue has space for a message
+    ///
+    /// Otherwise the task is woken once a message is dequeued.
+    pub fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let capacity = match self.capacity {
+            Some(capacity) => capacity,
+            None => return Poll::Ready(())
+        };
+
+        if self.len.load(Ordering::SeqCst) < capacity {
+            return Poll::Ready(());
+        }
+
+        self.waiting.register(cx.waker());
+
+        // check again in case a message was dequeued before registering
+        if self.len.load(Ordering::SeqCst) < capacity {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
     }
 }
 
 pub struct QueueReader<Msg: Message> {
     inner: Mutex<QueueReaderInner<Msg>>,
+    len: Arc<AtomicUsize>,
+    waiting: Arc<Waiting>,
 }
 
 struct QueueReaderInner<Msg: Message> {
@@ -50,23 +138,32 @@ impl<Msg: Message> QueueReader<Msg> {
     #[allow(dead_code)]
     pub fn dequeue(&self) -> Envelope<Msg> {
         let mut inner = self.inner.lock().unwrap();
-        if let Some(item) = inner.next_item.take() {
+        let item = if let Some(item) = inner.next_item.take() {
             item
         } else {
             inner.rx.recv().unwrap()
-        }
-        
+        };
+
+        self.len.fetch_sub(1, Ordering::SeqCst);
+        self.waiting.wake();
+        item
     }
 
     pub fn try_dequeue(&self) -> DequeueResult<Envelope<Msg>> {
         let mut inner = self.inner.lock().unwrap();
-        if let Some(item) = inner.next_item.take() {
+        let item = if let Some(item) = inner.next_item.take() {
             Ok(item)
         } else {
             inner.rx.try_recv().map_err(|_| QueueEmpty)
+        };
+
+        if item.is_ok() {
+            self.len.fetch_sub(1, Ordering::SeqCst);
+            self.waiting.wake();
         }
+        item
     }
-    
+
     pub fn has_msgs(&self) -> bool {
         let mut inner = self.inner.lock().unwrap();
         inner.next_item.is_some() || {
@@ -83,7 +180,8 @@ impl<Msg: Message> QueueReader<Msg> {
 
 #[derive(Clone, Debug)]
 pub struct EnqueueError<T> {
-    pub msg: T
+    pub msg: T,
+    pub reason: TellError,
 }
 
 pub type EnqueueResult<Msg> = Result<(), EnqueueError<Envelope<Msg>>>;
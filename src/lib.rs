@@ -10,6 +10,7 @@ mod validate;
 
 pub mod actor;
 pub mod kernel;
+pub mod routing;
 pub mod system;
 
 use std::env;
@@ -31,6 +32,9 @@ pub fn load_config() -> Config {
     cfg.set_default("log.date_format", "%Y-%m-%d").unwrap();
     cfg.set_default("log.time_format", "%H:%M:%S%:z").unwrap();
     cfg.set_default("mailbox.msg_process_limit", 1000).unwrap();
+    cfg.set_default("mailbox.capacity", 0).unwrap();
+    cfg.set_default("dead_letters.max_logged", 10).unwrap();
+    cfg.set_default("dead_letters.interval_millis", 1000).unwrap();
     cfg.set_default("dispatcher.pool_size", 4).unwrap();
     cfg.set_default("scheduler.frequency_millis", 50).unwrap();
 
@@ -78,11 +82,13 @@ impl AnyMessage {
         where T: Any + Message
     {
         if self.one_time {
+            // leave the message in place if it isn't a `T`
             match self.msg.take() {
                 Some(m) => {
                     if m.is::<T>() {
                         Ok(*m.downcast::<T>().unwrap())
                     } else {
+                        self.msg = Some(m);
                         Err(())
                     }
                 },
@@ -115,6 +121,6 @@ pub mod actors {
     pub use crate::actor::*;
     pub use crate::system::{
         ActorSystem, SystemBuilder, SystemMsg,
-        SystemEvent, Run, Timer
+        SystemEvent, EventStream, Inbox, Run, Timer
     };
 }
@@ -0,0 +1,171 @@
+use log::warn;
+
+use crate::{
+    actor::{
+        Actor, ActorReference, BoxActorProd, Props, Sender
+    },
+    kernel::{mailbox::SharedQueue, provider::mailbox_capacity},
+    system::{SystemMsg, SystemEvent},
+    routing::{Routee, RouterMsg, RouterCtx, pool::no_routees}
+};
+
+/// A pool router whose routees share a single mailbox
+///
+/// Messages sent to a balancing pool are placed on a queue that is shared
+/// by all of its routees. Whichever routee is free next takes the next
+/// message, so a routee that is busy with a slow message never delays
+/// the messages queued behind it.
+///
+/// Routees are created and supervised by the router in the same way
+/// as `Pool`. Since the queue is shared, `RouterMsg::Broadcast` can't
+/// guarantee that each routee receives a copy of the message. Instead,
+/// a broadcast message is queued once per routee and each copy is
+/// processed by whichever routee is free.
+///
+/// The shared queue is bounded by `mailbox.capacity` in the same way
+/// as the mailbox of any other user actor. Messages remaining in the
+/// shared queue when the last routee terminates are sent to dead letters.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// # use riker::routing::*;
+/// #[derive(Clone, Debug)]
+/// struct Resize {
+///     image: String,
+/// }
+///
+/// struct Worker;
+///
+/// impl Actor for Worker {
+///     type Msg = Resize;
+///
+///     fn recv(&mut self, _ctx: &Context<Resize>, _msg: Resize, _sender: Sender) {
+///         // resizing can take a while, other workers take the next messages
+///     }
+/// }
+///
+/// // main
+/// let sys = ActorSystem::new().unwrap();
+///
+/// let props = BalancingPool::props(Props::new(|| Worker), 5);
+/// let router = sys.actor_of(props, "resizers").unwrap();
+///
+/// router.tell(Resize { image: "cat.png".into() }, None);
+/// ```
+pub struct BalancingPool<A: Actor> {
+    props: BoxActorProd<A>,
+    nr_of_instances: usize,
+    // created in `pre_start`, once the system's settings are available
+    queue: Option<SharedQueue<A::Msg>>,
+    routees: Vec<Routee<A::Msg>>,
+    counter: usize,
+}
+
+impl<A> BalancingPool<A>
+    where A: Actor
+{
+    fn new((props, nr_of_instances): (BoxActorProd<A>, usize)) -> Self {
+        BalancingPool {
+            props,
+            nr_of_instances,
+            queue: None,
+            routees: Vec::new(),
+            counter: 0,
+        }
+    }
+
+    /// Returns `Props` for a balancing pool router of `nr_of_instances`
+    /// routees created from `props`
+    pub fn props(props: BoxActorProd<A>,
+                nr_of_instances: usize) -> BoxActorProd<BalancingPool<A>> {
+        Props::new_args(BalancingPool::new, (props, nr_of_instances))
+    }
+
+    fn add_routee(&mut self, ctx: &RouterCtx<A::Msg>) {
+        let queue = match self.queue {
+            Some(ref queue) => queue,
+            None => return
+        };
+
+        let name = format!("routee-{}", self.counter);
+        self.counter += 1;
+
+        let mailbox = queue.mailbox(ctx.system.sys_settings().msg_process_limit);
+        let actor = ctx.system
+            .provider
+            .create_actor_with_mailbox(self.props.clone(),
+                                        &name,
+                                        &ctx.myself().into(),
+                                        &ctx.system,
+                                        mailbox);
+
+        match actor {
+            Ok(actor) => self.routees.push(Routee::new(actor)),
+            Err(e) => warn!("Router {} failed to create routee: {}", ctx.myself.path(), e)
+        }
+    }
+
+    // Any routee can process the message since the queue is shared.
+    // Sending through an idle routee makes sure the message is picked up
+    // without waiting for a busy routee to finish its current run.
+    fn enqueue(&self, msg: A::Msg, sender: Sender) {
+        let routee = self.routees
+            .iter()
+            .find(|r| !r.is_scheduled())
+            .unwrap_or(&self.routees[0]);
+
+        routee.tell(msg, sender);
+    }
+}
+
+impl<A> Actor for BalancingPool<A>
+    where A: Actor
+{
+    type Msg = RouterMsg<A::Msg>;
+
+    fn pre_start(&mut self, ctx: &RouterCtx<A::Msg>) {
+        let capacity = mailbox_capacity(&ctx.myself().into(), &ctx.system);
+        self.queue = Some(SharedQueue::new(capacity));
+
+        for _ in 0..self.nr_of_instances {
+            self.add_routee(ctx);
+        }
+    }
+
+    fn recv(&mut self,
+            ctx: &RouterCtx<A::Msg>,
+            msg: RouterMsg<A::Msg>,
+            sender: Sender) {
+
+        match msg {
+            RouterMsg::Route(msg) | RouterMsg::Broadcast(msg) if self.routees.is_empty() => {
+                no_routees(ctx, msg, sender);
+            }
+            RouterMsg::Route(msg) => self.enqueue(msg, sender),
+            RouterMsg::Broadcast(msg) => {
+                for _ in 0..self.routees.len() {
+                    self.enqueue(msg.clone(), sender.clone());
+                }
+            }
+            RouterMsg::Resize => {}
+        }
+    }
+
+    fn sys_recv(&mut self,
+                ctx: &RouterCtx<A::Msg>,
+                msg: SystemMsg,
+                _: Sender) {
+        if let SystemMsg::Event(SystemEvent::ActorTerminated(terminated)) = msg {
+            let len = self.routees.len();
+            self.routees.retain(|r| r.path() != terminated.actor.path());
+
+            if len > 0 && self.routees.is_empty() {
+                if let Some(ref queue) = self.queue {
+                    queue.flush_to_deadletters(&ctx.myself().into(), &ctx.system);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    collections::{BTreeMap, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+    sync::Arc
+};
+
+use crate::{
+    Message,
+    actor::Sender,
+    routing::{Routee, RouterCtx, RoutingLogic}
+};
+
+/// Provides the key used by `ConsistentHashing` to select a routee
+///
+/// Messages returning the same key are routed to the same routee.
+pub trait ConsistentHashable {
+    fn consistent_hash_key(&self) -> u64;
+}
+
+/// Routes messages with the same key to the same routee
+///
+/// Each routee is placed on a hash ring at `virtual_nodes` positions,
+/// derived from the routee's path. A message is routed to the routee
+/// at the first position on the ring following the message's key.
+///
+/// Since ring positions depend only on routee paths, adding or removing
+/// a routee only moves the keys adjacent to that routee's positions.
+/// All other keys continue to be routed to the same routee.
+/// A routee that is restarted keeps its path and therefore its keys.
+#[derive(Clone)]
+pub struct ConsistentHashing<Msg> {
+    key: Arc<dyn Fn(&Msg) -> u64 + Send + Sync>,
+    virtual_nodes: usize,
+    ring: BTreeMap<u64, usize>,
+}
+
+impl<Msg> ConsistentHashing<Msg>
+    where Msg: Message
+{
+    /// Creates consistent hashing routing logic using `key` to
+    /// provide each message's key
+    pub fn new<F>(key: F) -> Self
+        where F: Fn(&Msg) -> u64 + Send + Sync + 'static
+    {
+        ConsistentHashing {
+            key: Arc::new(key),
+            virtual_nodes: 10,
+            ring: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the number of positions each routee occupies on the hash ring.
+    ///
+    /// A higher number results in keys being more evenly distributed
+    /// across routees. Default is 10.
+    pub fn virtual_nodes(self, virtual_nodes: usize) -> Self {
+        ConsistentHashing { virtual_nodes: virtual_nodes.max(1), .. self }
+    }
+
+    fn routee_for(&self, key: u64) -> Option<usize> {
+        let key = hash(&key);
+
+        self.ring
+            .range(key..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, idx)| *idx)
+    }
+}
+
+impl<Msg> Default for ConsistentHashing<Msg>
+    where Msg: Message + ConsistentHashable
+{
+    fn default() -> Self {
+        ConsistentHashing::new(|msg: &Msg| msg.consistent_hash_key())
+    }
+}
+
+impl<Msg> RoutingLogic<Msg> for ConsistentHashing<Msg>
+    where Msg: Message
+{
+    fn route(&mut self,
+                _: &RouterCtx<Msg>,
+                routees: &[Routee<Msg>],
+                msg: Msg,
+                sender: Sender) {
+
+        let key = (self.key)(&msg);
+        if let Some(routee) = self.routee_for(key).and_then(|idx| routees.get(idx)) {
+            routee.tell(msg, sender);
+        }
+    }
+
+    fn routees_changed(&mut self, routees: &[Routee<Msg>]) {
+        self.ring.clear();
+
+        for (idx, routee) in routees.iter().enumerate() {
+            for node in 0..self.virtual_nodes {
+                let pos = hash(&(routee.path().to_string(), node));
+                self.ring.insert(pos, idx);
+            }
+        }
+    }
+}
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,178 @@
+use std::marker::PhantomData;
+
+use log::warn;
+
+use crate::{
+    Message,
+    actor::{
+        Actor, ActorReference, ActorSelection, ActorSelectionFactory,
+        BasicActorRef, BoxActorProd, Props, Sender, Tell, Subscribe, SysTopic
+    },
+    actor::channel::EventSub,
+    system::{SystemMsg, SystemEvent},
+    routing::{Routee, RouterMsg, RouterCtx, RoutingLogic, pool::no_routees}
+};
+
+/// A router that routes messages to existing actors selected by path
+///
+/// Unlike `Pool`, a group router doesn't create or supervise its
+/// routees. Routees are the actors matching any of the given paths,
+/// which are resolved using `ActorSelection` and so follow the same
+/// rules as `select`, e.g. `/user/shards/*`.
+///
+/// Membership is kept up to date by subscribing to the `ActorCreated`
+/// and `ActorTerminated` system events. Actors created after the router
+/// that match one of the paths are added to the group and actors that
+/// are terminated are removed.
+///
+/// Since routees are only known by their `BasicActorRef`, messages are
+/// sent to them untyped. Messages not supported by a routee are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// # use riker::routing::*;
+/// #[derive(Clone, Debug)]
+/// struct Query;
+///
+/// struct Shard;
+///
+/// impl Actor for Shard {
+///     type Msg = Query;
+///
+///     fn recv(&mut self, _ctx: &Context<Query>, _msg: Query, _sender: Sender) {}
+/// }
+///
+/// // main
+/// let sys = ActorSystem::new().unwrap();
+///
+/// sys.actor_of(Props::new(|| Shard), "shard-1").unwrap();
+/// sys.actor_of(Props::new(|| Shard), "shard-2").unwrap();
+///
+/// let paths = vec!["/user/shard-1".into(), "/user/shard-2".into()];
+/// let props = Group::<Query, _>::props(paths, SmallestMailbox);
+/// let router = sys.actor_of(props, "shard-router").unwrap();
+///
+/// router.tell(Query, None);
+/// ```
+pub struct Group<Msg: Message, L> {
+    paths: Vec<String>,
+    logic: L,
+    selections: Vec<ActorSelection>,
+    routees: Vec<Routee<Msg>>,
+    msg: PhantomData<Msg>,
+}
+
+impl<Msg, L> Group<Msg, L>
+    where Msg: Message, L: RoutingLogic<Msg>
+{
+    fn new((paths, logic): (Vec<String>, L)) -> Self {
+        Group {
+            paths,
+            logic,
+            selections: Vec::new(),
+            routees: Vec::new(),
+            msg: PhantomData,
+        }
+    }
+
+    /// Returns `Props` for a group router routing messages to the
+    /// actors matching `paths` using `logic`
+    pub fn props(paths: Vec<String>, logic: L) -> BoxActorProd<Group<Msg, L>> {
+        Props::new_args(Group::new, (paths, logic))
+    }
+
+    fn add_routee(&mut self, actor: BasicActorRef) -> bool {
+        if self.routees.iter().any(|r| r.actor() == actor) {
+            false
+        } else {
+            self.routees.push(Routee::basic(actor));
+            true
+        }
+    }
+
+    fn is_selected(&self, actor: &BasicActorRef) -> bool {
+        self.selections
+            .iter()
+            .any(|sel| sel.matches(&actor.path().to_string()))
+    }
+}
+
+impl<Msg, L> Actor for Group<Msg, L>
+    where Msg: Message, L: RoutingLogic<Msg>
+{
+    type Msg = RouterMsg<Msg>;
+
+    fn pre_start(&mut self, ctx: &RouterCtx<Msg>) {
+        let sub = EventSub(ctx.myself().into());
+        for topic in [SysTopic::ActorCreated, SysTopic::ActorTerminated] {
+            ctx.system
+                .sys_events()
+                .tell(Subscribe { topic: topic.into(), actor: Box::new(sub.clone()) }, None);
+        }
+
+        self.selections = self.paths
+            .iter()
+            .filter_map(|path| {
+                ctx.select(path)
+                    .map_err(|e| warn!("Router {} ignoring invalid path: {}", ctx.myself.path(), e))
+                    .ok()
+            })
+            .collect();
+
+        let actors: Vec<BasicActorRef> = self.selections
+            .iter()
+            .flat_map(|sel| sel.resolve())
+            .collect();
+
+        for actor in actors {
+            self.add_routee(actor);
+        }
+
+        self.logic.routees_changed(&self.routees);
+    }
+
+    fn recv(&mut self,
+            ctx: &RouterCtx<Msg>,
+            msg: RouterMsg<Msg>,
+            sender: Sender) {
+
+        match msg {
+            RouterMsg::Route(msg) => {
+                if self.routees.is_empty() {
+                    no_routees(ctx, msg, sender);
+                } else {
+                    self.logic.route(ctx, &self.routees, msg, sender);
+                }
+            }
+            RouterMsg::Broadcast(msg) => {
+                for routee in self.routees.iter() {
+                    routee.tell(msg.clone(), sender.clone());
+                }
+            }
+            RouterMsg::Resize => {}
+        }
+    }
+
+    fn sys_recv(&mut self,
+                _: &RouterCtx<Msg>,
+                msg: SystemMsg,
+                _: Sender) {
+        let changed = match msg {
+            SystemMsg::Event(SystemEvent::ActorCreated(created)) => {
+                self.is_selected(&created.actor) && self.add_routee(created.actor)
+            }
+            SystemMsg::Event(SystemEvent::ActorTerminated(terminated)) => {
+                let len = self.routees.len();
+                self.routees.retain(|r| r.actor() != terminated.actor);
+                self.routees.len() != len
+            }
+            _ => false
+        };
+
+        if changed {
+            self.logic.routees_changed(&self.routees);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+pub(crate) mod balancing;
+pub(crate) mod consistent_hashing;
+pub(crate) mod group;
+pub(crate) mod pool;
+pub(crate) mod resizer;
+pub(crate) mod scatter_gather;
+pub(crate) mod smallest_mailbox;
+pub(crate) mod tail_chopping;
+
+use std::fmt;
+
+use crate::{
+    Message,
+    actor::{
+        ActorRef, ActorReference, ActorPath, BasicActorRef,
+        Context, Sender, Tell
+    }
+};
+
+// Public riker::routing API
+pub use self::{
+    balancing::BalancingPool,
+    consistent_hashing::{ConsistentHashing, ConsistentHashable},
+    group::Group,
+    pool::Pool,
+    resizer::Resizer,
+    scatter_gather::ScatterGatherFirstCompleted,
+    smallest_mailbox::SmallestMailbox,
+    tail_chopping::TailChopping
+};
+
+/// Messages accepted by routers
+///
+/// Since `RouterMsg<Msg>` implements `From<Msg>`, messages can be
+/// sent to a router using `tell` in the same way they would be sent
+/// directly to one of its routees. These will be routed using the
+/// router's routing logic.
+#[derive(Clone, Debug)]
+pub enum RouterMsg<Msg: Message> {
+    /// Route the message to the routee(s) selected by the routing logic
+    Route(Msg),
+
+    /// Send the message to all routees
+    Broadcast(Msg),
+
+    /// Resize the pool using the router's `Resizer`.
+    ///
+    /// Sent periodically by resizable pool routers to themselves.
+    Resize,
+}
+
+impl<Msg: Message> From<Msg> for RouterMsg<Msg> {
+    fn from(msg: Msg) -> Self {
+        RouterMsg::Route(msg)
+    }
+}
+
+pub type RouterCtx<Msg> = Context<RouterMsg<Msg>>;
+
+/// An actor that a router routes messages to
+#[derive(Clone)]
+pub struct Routee<Msg: Message> {
+    actor: RouteeRef<Msg>,
+}
+
+// Pool routees are created by the router and are typed.
+// Group routees are found by selection and are only known as
+// `BasicActorRef`, so messages are sent using `try_tell`.
+#[derive(Clone)]
+enum RouteeRef<Msg: Message> {
+    Typed(ActorRef<Msg>),
+    Basic(BasicActorRef),
+}
+
+impl<Msg: Message> Routee<Msg> {
+    pub(crate) fn new(actor: ActorRef<Msg>) -> Self {
+        Routee {
+            actor: RouteeRef::Typed(actor)
+        }
+    }
+
+    pub(crate) fn basic(actor: BasicActorRef) -> Self {
+        Routee {
+            actor: RouteeRef::Basic(actor)
+        }
+    }
+
+    /// Send a message to the routee
+    pub fn tell(&self, msg: Msg, sender: Sender) {
+        match self.actor {
+            RouteeRef::Typed(ref actor) => actor.tell(msg, sender),
+            RouteeRef::Basic(ref actor) => {
+                let _ = actor.try_tell_supported(msg, sender);
+            }
+        }
+    }
+
+    /// Routee path
+    pub fn path(&self) -> &ActorPath {
+        match self.actor {
+            RouteeRef::Typed(ref actor) => actor.path(),
+            RouteeRef::Basic(ref actor) => actor.path()
+        }
+    }
+
+    /// Returns the `BasicActorRef` of the routee
+    pub fn actor(&self) -> BasicActorRef {
+        match self.actor {
+            RouteeRef::Typed(ref actor) => actor.clone().into(),
+            RouteeRef::Basic(ref actor) => actor.clone()
+        }
+    }
+
+    /// Number of messages waiting in the routee's mailbox
+    pub fn mailbox_len(&self) -> usize {
+        match self.actor {
+            RouteeRef::Typed(ref actor) => actor.mailbox_len(),
+            RouteeRef::Basic(ref actor) => actor.mailbox_len()
+        }
+    }
+
+    /// Returns true if the routee has no messages queued
+    /// and is not currently processing messages
+    pub fn is_idle(&self) -> bool {
+        self.mailbox_len() == 0 && !self.is_scheduled()
+    }
+
+    pub(crate) fn is_scheduled(&self) -> bool {
+        match self.actor {
+            RouteeRef::Typed(ref actor) => actor.cell.is_scheduled(),
+            RouteeRef::Basic(ref actor) => actor.cell.is_scheduled()
+        }
+    }
+}
+
+impl<Msg: Message> fmt::Debug for Routee<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Routee[{:?}]", self.path())
+    }
+}
+
+/// Decides which routee(s) a message sent to a router is delivered to
+///
+/// Routing logic is provided to a router when it is created and is
+/// passed to the router's `Props`, so it must be `Clone`, `Send` and `Sync`.
+pub trait RoutingLogic<Msg: Message>: Clone + Send + Sync + 'static {
+    /// Routes a message to one or more of the routees.
+    ///
+    /// `routees` is never empty. Messages sent to a router without
+    /// any routees are sent to dead letters.
+    fn route(&mut self,
+                ctx: &RouterCtx<Msg>,
+                routees: &[Routee<Msg>],
+                msg: Msg,
+                sender: Sender);
+
+    /// Invoked whenever routees are added to or removed from the router
+    fn routees_changed(&mut self, _routees: &[Routee<Msg>]) {
+
+    }
+}
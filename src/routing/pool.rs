@@ -0,0 +1,246 @@
+use log::warn;
+use uuid::Uuid;
+
+use crate::{
+    Message,
+    actor::{
+        Actor, ActorRefFactory, ActorReference, BoxActorProd, Props,
+        Sender, Tell, Publish, DeadLetter, DeadLetterReason
+    },
+    system::{ActorSystem, SystemMsg, SystemEvent, RouterResized, Timer},
+    routing::{Routee, RouterMsg, RouterCtx, RoutingLogic, Resizer}
+};
+
+/// A router that creates and supervises its own routees
+///
+/// When started, a pool router creates `nr_of_instances` routees as its
+/// children from the given `Props`. Messages sent to the router are
+/// routed to a routee using the provided `RoutingLogic`.
+///
+/// Routees that fail are supervised by the router using the default
+/// `Strategy::Restart`. Since restarting an actor keeps its path and
+/// `ActorRef`, routing logic that depends on a routee's identity (such
+/// as `ConsistentHashing`) continues to route to the same routee.
+/// Routees that are terminated are removed from the pool.
+///
+/// A pool created using `props_with_resizer` is resized periodically
+/// by the given `Resizer`. Each time the pool is resized, a
+/// `RouterResized` event is published to the system events channel.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// # use riker::routing::*;
+/// #[derive(Clone, Debug)]
+/// struct Order {
+///     customer_id: u64,
+/// }
+///
+/// struct Worker;
+///
+/// impl Actor for Worker {
+///     type Msg = Order;
+///
+///     fn recv(&mut self, _ctx: &Context<Order>, _msg: Order, _sender: Sender) {
+///         // orders for the same customer are always processed by the same worker
+///     }
+/// }
+///
+/// // main
+/// let sys = ActorSystem::new().unwrap();
+///
+/// let logic = ConsistentHashing::new(|order: &Order| order.customer_id);
+/// let props = Pool::props(Props::new(|| Worker), 5, logic);
+/// let router = sys.actor_of(props, "workers").unwrap();
+///
+/// router.tell(Order { customer_id: 42 }, None);
+/// ```
+pub struct Pool<A: Actor, L> {
+    props: BoxActorProd<A>,
+    nr_of_instances: usize,
+    logic: L,
+    routees: Vec<Routee<A::Msg>>,
+    counter: usize,
+    resizer: Option<Resizer>,
+    draining: Vec<Routee<A::Msg>>,
+    resize_schedule: Option<(ActorSystem, Uuid)>,
+}
+
+type PoolArgs<A, L> = (BoxActorProd<A>, usize, L, Option<Resizer>);
+
+impl<A, L> Pool<A, L>
+    where A: Actor, L: RoutingLogic<A::Msg>
+{
+    fn new((props, nr_of_instances, logic, resizer): PoolArgs<A, L>) -> Self {
+        Pool {
+            props,
+            nr_of_instances,
+            logic,
+            routees: Vec::new(),
+            counter: 0,
+            resizer,
+            draining: Vec::new(),
+            resize_schedule: None,
+        }
+    }
+
+    /// Returns `Props` for a pool router of `nr_of_instances` routees
+    /// created from `props`, routing messages using `logic`
+    pub fn props(props: BoxActorProd<A>,
+                nr_of_instances: usize,
+                logic: L) -> BoxActorProd<Pool<A, L>> {
+        Props::new_args(Pool::new, (props, nr_of_instances, logic, None))
+    }
+
+    /// Returns `Props` for a pool router of routees created from `props`,
+    /// routing messages using `logic` and resized using `resizer`.
+    ///
+    /// The pool starts with `lower_bound` routees.
+    pub fn props_with_resizer(props: BoxActorProd<A>,
+                            logic: L,
+                            resizer: Resizer) -> BoxActorProd<Pool<A, L>> {
+        let nr_of_instances = resizer.lower_bound;
+        Props::new_args(Pool::new, (props, nr_of_instances, logic, Some(resizer)))
+    }
+
+    fn add_routee(&mut self, ctx: &RouterCtx<A::Msg>) {
+        let name = format!("routee-{}", self.counter);
+        self.counter += 1;
+
+        match ctx.actor_of(self.props.clone(), &name) {
+            Ok(actor) => self.routees.push(Routee::new(actor)),
+            Err(e) => warn!("Router {} failed to create routee: {}", ctx.myself.path(), e)
+        }
+    }
+
+    fn resize(&mut self, ctx: &RouterCtx<A::Msg>) {
+        self.stop_drained(ctx);
+
+        let delta = match self.resizer {
+            Some(ref resizer) => resizer.capacity_delta(&self.routees),
+            None => return
+        };
+
+        if delta == 0 {
+            return;
+        }
+
+        let previous = self.routees.len();
+
+        if delta > 0 {
+            for _ in 0..delta {
+                self.add_routee(ctx);
+            }
+        } else {
+            // removed routees stop receiving messages
+            // and are stopped once they've drained
+            for _ in 0..-delta {
+                let idx = self.routees
+                    .iter()
+                    .rposition(|r| r.is_idle())
+                    .unwrap_or(self.routees.len() - 1);
+
+                let routee = self.routees.remove(idx);
+                self.draining.push(routee);
+            }
+            self.stop_drained(ctx);
+        }
+
+        self.logic.routees_changed(&self.routees);
+
+        let resized = RouterResized {
+            router: ctx.myself().into(),
+            previous,
+            size: self.routees.len()
+        };
+        ctx.system.publish_event(resized.into());
+    }
+
+    fn stop_drained(&mut self, ctx: &RouterCtx<A::Msg>) {
+        self.draining.retain(|routee| {
+            if routee.is_idle() {
+                ctx.stop(routee.actor());
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+impl<A, L> Actor for Pool<A, L>
+    where A: Actor, L: RoutingLogic<A::Msg>
+{
+    type Msg = RouterMsg<A::Msg>;
+
+    fn pre_start(&mut self, ctx: &RouterCtx<A::Msg>) {
+        for _ in 0..self.nr_of_instances {
+            self.add_routee(ctx);
+        }
+
+        self.logic.routees_changed(&self.routees);
+
+        if let Some(ref resizer) = self.resizer {
+            let id = ctx.schedule(resizer.interval,
+                                    resizer.interval,
+                                    ctx.myself(),
+                                    None,
+                                    RouterMsg::Resize);
+            self.resize_schedule = Some((ctx.system.clone(), id));
+        }
+    }
+
+    fn recv(&mut self,
+            ctx: &RouterCtx<A::Msg>,
+            msg: RouterMsg<A::Msg>,
+            sender: Sender) {
+
+        match msg {
+            RouterMsg::Route(msg) => {
+                if self.routees.is_empty() {
+                    no_routees(ctx, msg, sender);
+                } else {
+                    self.logic.route(ctx, &self.routees, msg, sender);
+                }
+            }
+            RouterMsg::Broadcast(msg) => {
+                for routee in self.routees.iter() {
+                    routee.tell(msg.clone(), sender.clone());
+                }
+            }
+            RouterMsg::Resize => self.resize(ctx),
+        }
+    }
+
+    fn post_stop(&mut self) {
+        if let Some((ref sys, id)) = self.resize_schedule {
+            sys.cancel_schedule(id);
+        }
+    }
+
+    // Routees are children of the router so the router receives
+    // ActorTerminated when a routee is terminated
+    fn sys_recv(&mut self,
+                _: &RouterCtx<A::Msg>,
+                msg: SystemMsg,
+                _: Sender) {
+        if let SystemMsg::Event(SystemEvent::ActorTerminated(terminated)) = msg {
+            let len = self.routees.len();
+            self.routees.retain(|r| r.path() != terminated.actor.path());
+            self.draining.retain(|r| r.path() != terminated.actor.path());
+
+            if self.routees.len() != len {
+                self.logic.routees_changed(&self.routees);
+            }
+        }
+    }
+}
+
+pub(crate) fn no_routees<Msg: Message>(ctx: &RouterCtx<Msg>, msg: Msg, sender: Sender) {
+    let dl = DeadLetter::new(msg, DeadLetterReason::NoRoutees, sender, ctx.myself().into());
+
+    ctx.system
+        .dead_letters()
+        .tell(Publish { topic: "dead_letter".into(), msg: dl }, None);
+}
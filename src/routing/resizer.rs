@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use crate::{
+    Message,
+    routing::Routee
+};
+
+/// Grows and shrinks a pool router's routees based on mailbox pressure
+///
+/// A resizable pool samples its routees every `interval`. A routee is
+/// considered under pressure when it has at least `pressure_threshold`
+/// messages waiting in its mailbox.
+///
+/// - If every routee is under pressure the pool grows by `rampup_rate`
+///   of its current size (rounded up).
+/// - If less than `backoff_threshold` of the routees are under pressure
+///   the pool shrinks by `backoff_rate` of its current size (rounded up).
+///
+/// The pool size always stays within `lower_bound..=upper_bound`.
+///
+/// Routees removed from a pool no longer receive messages and are
+/// stopped once their mailbox is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use riker::routing::Resizer;
+/// let resizer = Resizer::new(2, 10)
+///                 .rampup_rate(0.5)
+///                 .interval(Duration::from_millis(500));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Resizer {
+    pub(crate) lower_bound: usize,
+    pub(crate) upper_bound: usize,
+    pub(crate) pressure_threshold: usize,
+    pub(crate) rampup_rate: f64,
+    pub(crate) backoff_threshold: f64,
+    pub(crate) backoff_rate: f64,
+    pub(crate) interval: Duration,
+}
+
+impl Resizer {
+    /// Creates a resizer keeping the pool size between
+    /// `lower_bound` and `upper_bound` inclusive
+    pub fn new(lower_bound: usize, upper_bound: usize) -> Self {
+        Resizer {
+            lower_bound,
+            upper_bound: upper_bound.max(lower_bound),
+            pressure_threshold: 1,
+            rampup_rate: 0.2,
+            backoff_threshold: 0.3,
+            backoff_rate: 0.1,
+            interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Number of queued messages at which a routee is considered
+    /// under pressure. Default is 1.
+    pub fn pressure_threshold(self, pressure_threshold: usize) -> Self {
+        Resizer { pressure_threshold: pressure_threshold.max(1), .. self }
+    }
+
+    /// Proportion of the current size to grow by. Default is 0.2.
+    pub fn rampup_rate(self, rampup_rate: f64) -> Self {
+        Resizer { rampup_rate, .. self }
+    }
+
+    /// Proportion of routees under pressure below which the pool
+    /// shrinks. Default is 0.3.
+    pub fn backoff_threshold(self, backoff_threshold: f64) -> Self {
+        Resizer { backoff_threshold, .. self }
+    }
+
+    /// Proportion of the current size to shrink by. Default is 0.1.
+    pub fn backoff_rate(self, backoff_rate: f64) -> Self {
+        Resizer { backoff_rate, .. self }
+    }
+
+    /// How often routees are sampled. Default is 1 second.
+    pub fn interval(self, interval: Duration) -> Self {
+        Resizer { interval, .. self }
+    }
+
+    /// Returns the number of routees to add (positive)
+    /// or remove (negative)
+    pub(crate) fn capacity_delta<Msg: Message>(&self, routees: &[Routee<Msg>]) -> isize {
+        let current = routees.len();
+
+        let pressure = routees
+            .iter()
+            .filter(|r| r.mailbox_len() >= self.pressure_threshold)
+            .count();
+
+        let proposed = if current == 0 {
+            0
+        } else if pressure == current {
+            (self.rampup_rate * current as f64).ceil() as isize
+        } else if (pressure as f64 / current as f64) < self.backoff_threshold {
+            -(self.backoff_rate * current as f64).ceil() as isize
+        } else {
+            0
+        };
+
+        let target = (current as isize + proposed)
+            .max(self.lower_bound as isize)
+            .min(self.upper_bound as isize);
+
+        target - current as isize
+    }
+}
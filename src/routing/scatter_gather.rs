@@ -0,0 +1,188 @@
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration
+};
+
+use log::warn;
+use uuid::Uuid;
+
+use crate::{
+    Message,
+    actor::{
+        Actor, ActorRef, ActorRefFactory, ActorReference, BoxActorProd,
+        Context, Props, Sender, TmpActorRefFactory
+    },
+    system::Timer,
+    routing::{Routee, RouterCtx, RoutingLogic}
+};
+
+/// Sends each message to all routees and replies with the first answer
+///
+/// The message is sent to every routee with a temporary actor as its
+/// sender. The first reply received by the temporary actor is sent to the
+/// original sender of the message and any later replies are discarded.
+///
+/// If no reply is received `within` the given duration, the `timeout`
+/// reply is sent to the original sender instead.
+///
+/// Replies are correlated by type, so routees must reply to the sender
+/// with a message of type `R` using `try_tell`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use riker::actors::*;
+/// # use riker::routing::*;
+/// #[derive(Clone, Debug)]
+/// struct Lookup(u64);
+///
+/// #[derive(Clone, Debug)]
+/// enum LookupResult {
+///     Found(String),
+///     TimedOut,
+/// }
+///
+/// struct Replica;
+///
+/// impl Actor for Replica {
+///     type Msg = Lookup;
+///
+///     fn recv(&mut self, ctx: &Context<Lookup>, msg: Lookup, sender: Sender) {
+///         let result = LookupResult::Found(format!("value-{}", msg.0));
+///         sender.unwrap().try_tell(result, Some(ctx.myself().into())).unwrap();
+///     }
+/// }
+///
+/// // main
+/// let sys = ActorSystem::new().unwrap();
+///
+/// let logic = ScatterGatherFirstCompleted::new(Duration::from_millis(500),
+///                                                 LookupResult::TimedOut);
+/// let props = Pool::props(Props::new(|| Replica), 3, logic);
+/// let router = sys.actor_of(props, "replicas").unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ScatterGatherFirstCompleted<R> {
+    within: Duration,
+    timeout: R,
+}
+
+impl<R> ScatterGatherFirstCompleted<R>
+    where R: Message + Sync
+{
+    /// Creates scatter gather routing logic that replies with `timeout`
+    /// if no routee replies `within` the given duration
+    pub fn new(within: Duration, timeout: R) -> Self {
+        ScatterGatherFirstCompleted {
+            within,
+            timeout
+        }
+    }
+}
+
+impl<Msg, R> RoutingLogic<Msg> for ScatterGatherFirstCompleted<R>
+    where Msg: Message, R: Message + Sync
+{
+    fn route(&mut self,
+                ctx: &RouterCtx<Msg>,
+                routees: &[Routee<Msg>],
+                msg: Msg,
+                sender: Sender) {
+
+        let jobs = Arc::new(Mutex::new(Vec::new()));
+        if let Some(gatherer) = Gatherer::start(ctx, sender, jobs.clone()) {
+            // the timeout is registered before any routee can reply,
+            // otherwise the gatherer could stop without cancelling it
+            let id = schedule_timeout(ctx, &gatherer, self.within, self.timeout.clone());
+            jobs.lock().unwrap().push(id);
+
+            for routee in routees.iter() {
+                routee.tell(msg.clone(), Some(gatherer.clone().into()));
+            }
+        }
+    }
+}
+
+pub(crate) fn schedule_timeout<Msg, R>(ctx: &RouterCtx<Msg>,
+                                        gatherer: &ActorRef<R>,
+                                        within: Duration,
+                                        timeout: R) -> Uuid
+    where Msg: Message, R: Message
+{
+    // the timeout is sent by the gatherer to itself
+    // to distinguish it from replies sent by routees
+    ctx.schedule_once(within,
+                        gatherer.clone(),
+                        Some(gatherer.clone().into()),
+                        timeout)
+}
+
+/// Temporary actor that forwards the first reply it receives
+/// to the requester and then stops
+pub(crate) struct Gatherer<R> {
+    requester: Sender,
+    jobs: Arc<Mutex<Vec<Uuid>>>,
+    done: bool,
+    reply: PhantomData<R>,
+}
+
+impl<R: Message> Gatherer<R> {
+    fn new((requester, jobs): (Sender, Arc<Mutex<Vec<Uuid>>>)) -> Self {
+        Gatherer {
+            requester,
+            jobs,
+            done: false,
+            reply: PhantomData,
+        }
+    }
+
+    fn props(requester: Sender,
+            jobs: Arc<Mutex<Vec<Uuid>>>) -> BoxActorProd<Gatherer<R>> {
+        Props::new_args(Gatherer::new, (requester, jobs))
+    }
+
+    /// Starts a gatherer replying to `requester`. Scheduled jobs added
+    /// to `jobs` are cancelled once the gatherer has replied.
+    pub(crate) fn start<Msg: Message>(ctx: &RouterCtx<Msg>,
+                                        requester: Sender,
+                                        jobs: Arc<Mutex<Vec<Uuid>>>)
+                                        -> Option<ActorRef<R>> {
+        ctx.system
+            .tmp_actor_of(Gatherer::props(requester, jobs))
+            .map_err(|e| warn!("Router {} failed to create gatherer: {}", ctx.myself.path(), e))
+            .ok()
+    }
+}
+
+impl<R: Message> Actor for Gatherer<R> {
+    type Msg = R;
+
+    fn recv(&mut self,
+            ctx: &Context<R>,
+            msg: R,
+            sender: Sender) {
+
+        if self.done {
+            return;
+        }
+        self.done = true;
+
+        let is_timeout = match sender {
+            Some(ref s) => s.path() == ctx.myself.path(),
+            None => false
+        };
+        let sender = if is_timeout { None } else { sender };
+
+        if let Some(ref requester) = self.requester {
+            let _ = requester.try_tell(msg, sender);
+        }
+
+        for id in self.jobs.lock().unwrap().drain(..) {
+            ctx.cancel_schedule(id);
+        }
+
+        ctx.stop(&ctx.myself);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    Message,
+    actor::Sender,
+    routing::{Routee, RouterCtx, RoutingLogic}
+};
+
+/// Routes each message to the routee with the fewest queued messages
+///
+/// A routee that is idle, i.e. has an empty mailbox and is not currently
+/// processing messages, is always preferred. Otherwise the message is
+/// routed to the routee with the smallest mailbox.
+///
+/// Mailbox sizes are sampled at the time the message is routed, so under
+/// heavy load the selected routee might no longer have the smallest mailbox
+/// by the time the message is processed.
+#[derive(Clone, Debug, Default)]
+pub struct SmallestMailbox;
+
+impl<Msg> RoutingLogic<Msg> for SmallestMailbox
+    where Msg: Message
+{
+    fn route(&mut self,
+                _: &RouterCtx<Msg>,
+                routees: &[Routee<Msg>],
+                msg: Msg,
+                sender: Sender) {
+
+        let routee = routees
+            .iter()
+            .find(|r| r.is_idle())
+            .or_else(|| routees.iter().min_by_key(|r| r.mailbox_len()));
+
+        if let Some(routee) = routee {
+            routee.tell(msg, sender);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime}
+};
+
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::{
+    AnyMessage, Message,
+    actor::{BasicActorRef, Sender},
+    system::timer::{Job, OnceJob},
+    routing::{
+        Routee, RouterCtx, RoutingLogic,
+        scatter_gather::{Gatherer, schedule_timeout}
+    }
+};
+
+/// Sends each message to one routee at a time until one replies
+///
+/// The message is first sent to a random routee. If no reply has been
+/// received after `interval` the message is also sent to the next routee,
+/// and so on until a reply is received or all routees have been sent the
+/// message. The first reply is sent to the original sender of the message
+/// and any later replies are discarded.
+///
+/// If no reply is received `within` the given duration, the `timeout`
+/// reply is sent to the original sender instead.
+///
+/// This reduces tail latency in the same way as
+/// `ScatterGatherFirstCompleted` without sending every message to every
+/// routee. Routees must reply to the sender with a message of type `R`
+/// using `try_tell`.
+#[derive(Clone)]
+pub struct TailChopping<R> {
+    within: Duration,
+    interval: Duration,
+    timeout: R,
+}
+
+impl<R> TailChopping<R>
+    where R: Message + Sync
+{
+    /// Creates tail chopping routing logic that sends the message to an
+    /// additional routee every `interval`, replying with `timeout` if no
+    /// routee replies `within` the given duration
+    pub fn new(within: Duration, interval: Duration, timeout: R) -> Self {
+        TailChopping {
+            within,
+            interval,
+            timeout
+        }
+    }
+}
+
+impl<Msg, R> RoutingLogic<Msg> for TailChopping<R>
+    where Msg: Message, R: Message + Sync
+{
+    fn route(&mut self,
+                ctx: &RouterCtx<Msg>,
+                routees: &[Routee<Msg>],
+                msg: Msg,
+                sender: Sender) {
+
+        let jobs = Arc::new(Mutex::new(Vec::new()));
+        if let Some(gatherer) = Gatherer::start(ctx, sender, jobs.clone()) {
+            let mut order: Vec<&Routee<Msg>> = routees.iter().collect();
+            rand::thread_rng().shuffle(&mut order);
+
+            let gatherer_ref: BasicActorRef = gatherer.clone().into();
+
+            // all jobs are registered before the first routee can reply,
+            // otherwise the gatherer could stop without cancelling them
+            let mut ids = Vec::new();
+            for (i, routee) in order.iter().enumerate().skip(1) {
+                let delay = self.interval * i as u32;
+                if delay < self.within {
+                    ids.push(send_after(ctx, delay, routee, msg.clone(), &gatherer_ref));
+                }
+            }
+
+            ids.push(schedule_timeout(ctx, &gatherer, self.within, self.timeout.clone()));
+            jobs.lock().unwrap().extend(ids);
+
+            if let Some(first) = order.first() {
+                first.tell(msg, Some(gatherer_ref));
+            }
+        }
+    }
+}
+
+// Routees might only be known as `BasicActorRef` (e.g. group routees)
+// so the message is scheduled as `AnyMessage`
+fn send_after<Msg: Message>(ctx: &RouterCtx<Msg>,
+                            delay: Duration,
+                            routee: &Routee<Msg>,
+                            msg: Msg,
+                            gatherer: &BasicActorRef) -> Uuid {
+    let id = Uuid::new_v4();
+
+    let job = OnceJob {
+        id,
+        send_at: SystemTime::now() + delay,
+        receiver: routee.actor(),
+        sender: Some(gatherer.clone()),
+        msg: AnyMessage::new(msg, true)
+    };
+
+    let _ = ctx.system.timer.send(Job::Once(job));
+    id
+}
@@ -0,0 +1,223 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex}
+};
+
+use crate::{
+    Message, AnyMessage,
+    actor::{
+        Actor, ActorRef, ActorReference, ActorUri, BasicActorRef, Context,
+        Sender, Tell, BoxedTell
+    },
+    system::{SystemEvent, SystemMsg}
+};
+
+/// System wide event bus where subscribers are classified by message type
+///
+/// Unlike channels, the event stream doesn't need to be created and
+/// passed around. Every actor can reach it through
+/// `ctx.system.event_stream()`. Actors subscribe to a Rust type and
+/// receive every message of that type published to the stream,
+/// regardless of who published it.
+///
+/// Messages are matched by their exact type. Subscribing to a type
+/// doesn't subscribe to other types that convert into it.
+///
+/// Subscribers that terminate are unsubscribed automatically.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct OrderPlaced(u32);
+///
+/// struct Billing;
+///
+/// impl Actor for Billing {
+///     type Msg = OrderPlaced;
+///
+///     fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+///         ctx.system.event_stream().subscribe::<OrderPlaced>(ctx.myself());
+///     }
+///
+///     fn recv(&mut self,
+///             _ctx: &Context<Self::Msg>,
+///             msg: Self::Msg,
+///             _sender: Sender) {
+///         println!("Billing order: {}", msg.0);
+///     }
+/// }
+///
+/// # impl Billing {
+/// #     fn actor() -> BoxActor<OrderPlaced> {
+/// #         Box::new(Billing)
+/// #     }
+/// # }
+/// let sys = ActorSystem::new().unwrap();
+/// sys.actor_of(Props::new(Billing::actor), "billing").unwrap();
+///
+/// sys.event_stream().publish(OrderPlaced(42), None);
+/// ```
+#[derive(Clone)]
+pub struct EventStream {
+    actor: ActorRef<EventStreamMsg>,
+}
+
+impl EventStream {
+    pub(crate) fn new(actor: ActorRef<EventStreamMsg>) -> Self {
+        EventStream {
+            actor
+        }
+    }
+
+    /// Subscribes `actor` to messages of type `Msg`
+    pub fn subscribe<Msg: Message>(&self, actor: impl Tell<Msg>) {
+        let sub = EventSubscriber(Box::new(TypedSubscriber(Box::new(actor))));
+        self.actor.tell(EventStreamMsg::Subscribe(TypeId::of::<Msg>(), sub), None);
+    }
+
+    /// Unsubscribes `actor` from messages of type `Msg`
+    pub fn unsubscribe<Msg: Message>(&self, actor: &dyn ActorReference) {
+        let uri = actor.uri().clone();
+        self.actor.tell(EventStreamMsg::Unsubscribe(TypeId::of::<Msg>(), uri), None);
+    }
+
+    /// Unsubscribes `actor` from all message types
+    pub fn unsubscribe_all(&self, actor: &dyn ActorReference) {
+        let uri = actor.uri().clone();
+        self.actor.tell(EventStreamMsg::UnsubscribeAll(uri), None);
+    }
+
+    /// Publishes `msg` to all subscribers of its type
+    pub fn publish<Msg>(&self, msg: Msg, sender: impl Into<Option<BasicActorRef>>)
+        where Msg: Message
+    {
+        let any = Arc::new(Mutex::new(AnyMessage::new(msg, false)));
+        let publish = EventStreamMsg::Publish(TypeId::of::<Msg>(), any);
+        self.actor.tell(publish, sender.into());
+    }
+}
+
+impl fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EventStream[{:?}]", self.actor)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+pub enum EventStreamMsg {
+    Subscribe(TypeId, EventSubscriber),
+    Unsubscribe(TypeId, ActorUri),
+    UnsubscribeAll(ActorUri),
+    Publish(TypeId, Arc<Mutex<AnyMessage>>),
+}
+
+/// A subscriber with its message type erased
+trait Deliver: Send {
+    fn deliver(&self, msg: &mut AnyMessage, sender: Sender);
+
+    fn uri(&self) -> &ActorUri;
+
+    fn box_clone(&self) -> Box<dyn Deliver>;
+}
+
+struct TypedSubscriber<Msg>(BoxedTell<Msg>);
+
+impl<Msg: Message> Deliver for TypedSubscriber<Msg> {
+    fn deliver(&self, msg: &mut AnyMessage, sender: Sender) {
+        if let Ok(msg) = msg.take::<Msg>() {
+            self.0.tell(msg, sender);
+        }
+    }
+
+    fn uri(&self) -> &ActorUri {
+        self.0.uri()
+    }
+
+    fn box_clone(&self) -> Box<dyn Deliver> {
+        Box::new(TypedSubscriber(self.0.box_clone()))
+    }
+}
+
+#[doc(hidden)]
+pub struct EventSubscriber(Box<dyn Deliver>);
+
+impl Clone for EventSubscriber {
+    fn clone(&self) -> Self {
+        EventSubscriber(self.0.box_clone())
+    }
+}
+
+impl fmt::Debug for EventSubscriber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EventSubscriber[{:?}]", self.0.uri())
+    }
+}
+
+/// Actor backing the `EventStream`
+pub(crate) struct EventStreamActor {
+    subs: HashMap<TypeId, Vec<EventSubscriber>>,
+}
+
+impl EventStreamActor {
+    pub fn new() -> Box<dyn Actor<Msg=EventStreamMsg> + Send> {
+        Box::new(EventStreamActor {
+            subs: HashMap::new()
+        })
+    }
+
+    fn unsubscribe_all(&mut self, uri: &ActorUri) {
+        for subs in self.subs.values_mut() {
+            subs.retain(|s| s.0.uri() != uri);
+        }
+        self.subs.retain(|_, subs| !subs.is_empty());
+    }
+}
+
+impl Actor for EventStreamActor {
+    type Msg = EventStreamMsg;
+
+    fn recv(&mut self,
+            _: &Context<Self::Msg>,
+            msg: Self::Msg,
+            sender: Sender) {
+        match msg {
+            EventStreamMsg::Subscribe(type_id, sub) => {
+                self.subs.entry(type_id).or_default().push(sub);
+            }
+            EventStreamMsg::Unsubscribe(type_id, uri) => {
+                if let Some(subs) = self.subs.get_mut(&type_id) {
+                    subs.retain(|s| *s.0.uri() != uri);
+                    if subs.is_empty() {
+                        self.subs.remove(&type_id);
+                    }
+                }
+            }
+            EventStreamMsg::UnsubscribeAll(uri) => self.unsubscribe_all(&uri),
+            EventStreamMsg::Publish(type_id, msg) => {
+                if let Some(subs) = self.subs.get(&type_id) {
+                    let mut msg = msg.lock().unwrap();
+                    for sub in subs {
+                        sub.0.deliver(&mut msg, sender.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // The system subscribes the event stream to ActorTerminated events
+    // so that terminated subscribers are removed
+    fn sys_recv(&mut self,
+                _: &Context<Self::Msg>,
+                msg: SystemMsg,
+                _: Sender) {
+        if let SystemMsg::Event(SystemEvent::ActorTerminated(terminated)) = msg {
+            self.unsubscribe_all(terminated.actor.uri());
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex},
+    task::{self, Poll, Waker},
+    time::{Duration, Instant}
+};
+
+use futures::future::poll_fn;
+
+use crate::{
+    Message,
+    actor::{
+        Actor, ActorRef, ActorRefFactory, Context, CreateError, Props, Sender,
+        TmpActorRefFactory
+    },
+    system::ActorSystem
+};
+
+/// Receives messages sent to an actor from code outside of the system
+///
+/// An inbox is backed by a temporary actor under `/temp`, so it can be
+/// used as the sender of messages to actors and receive their replies,
+/// or subscribe to channels. Messages are received by blocking with
+/// `receive` or asynchronously with `next`.
+///
+/// Dropping the inbox stops its actor.
+///
+/// # Examples
+///
+/// ```
+/// # use riker::actors::*;
+/// use std::time::Duration;
+///
+/// struct Echo;
+///
+/// impl Actor for Echo {
+///     type Msg = String;
+///
+///     fn recv(&mut self,
+///             ctx: &Context<Self::Msg>,
+///             msg: Self::Msg,
+///             sender: Sender) {
+///         sender.unwrap().try_tell(msg, Some(ctx.myself().into())).unwrap();
+///     }
+/// }
+///
+/// let sys = ActorSystem::new().unwrap();
+/// let echo = sys.actor_of(Props::new(|| Echo), "echo").unwrap();
+///
+/// let inbox = sys.inbox::<String>().unwrap();
+/// echo.tell("hello".to_string(), inbox.sender());
+///
+/// let reply = inbox.receive(Duration::from_secs(1));
+/// assert_eq!(reply, Some("hello".to_string()));
+/// ```
+pub struct Inbox<Msg: Message> {
+    actor: ActorRef<Msg>,
+    queue: Arc<InboxQueue<Msg>>,
+    sys: ActorSystem,
+}
+
+impl<Msg> Inbox<Msg>
+    where Msg: Message
+{
+    pub(crate) fn new(sys: &ActorSystem) -> Result<Self, CreateError> {
+        let queue = Arc::new(InboxQueue::new());
+        let props = Props::new_args(InboxActor::new, queue.clone());
+        let actor = sys.tmp_actor_of(props)?;
+
+        Ok(Inbox {
+            actor,
+            queue,
+            sys: sys.clone()
+        })
+    }
+
+    /// The actor receiving messages for this inbox
+    pub fn actor(&self) -> &ActorRef<Msg> {
+        &self.actor
+    }
+
+    /// Returns the inbox's actor as a `Sender`, so that replies are
+    /// received by the inbox
+    pub fn sender(&self) -> Sender {
+        Some(self.actor.clone().into())
+    }
+
+    /// Blocks until a message is received or `timeout` expires
+    ///
+    /// Returns `None` if no message was received in time.
+    pub fn receive(&self, timeout: Duration) -> Option<Msg> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.queue.state.lock().unwrap();
+
+        loop {
+            if let Some(msg) = state.msgs.pop_front() {
+                return Some(msg);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+
+            state = self.queue.ready.wait_timeout(state, deadline - now).unwrap().0;
+        }
+    }
+
+    /// Waits asynchronously for the next message
+    pub async fn next(&self) -> Msg {
+        poll_fn(|cx| self.poll_next(cx)).await
+    }
+
+    fn poll_next(&self, cx: &mut task::Context<'_>) -> Poll<Msg> {
+        let mut state = self.queue.state.lock().unwrap();
+
+        match state.msgs.pop_front() {
+            Some(msg) => Poll::Ready(msg),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<Msg> Drop for Inbox<Msg>
+    where Msg: Message
+{
+    fn drop(&mut self) {
+        self.sys.stop(&self.actor);
+    }
+}
+
+struct InboxQueue<Msg> {
+    state: Mutex<InboxState<Msg>>,
+    ready: Condvar,
+}
+
+struct InboxState<Msg> {
+    msgs: VecDeque<Msg>,
+    waker: Option<Waker>,
+}
+
+impl<Msg> InboxQueue<Msg> {
+    fn new() -> Self {
+        let state = InboxState {
+            msgs: VecDeque::new(),
+            waker: None
+        };
+
+        InboxQueue {
+            state: Mutex::new(state),
+            ready: Condvar::new()
+        }
+    }
+
+    fn push(&self, msg: Msg) {
+        let mut state = self.state.lock().unwrap();
+        state.msgs.push_back(msg);
+
+        self.ready.notify_one();
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Queues the messages it receives for an `Inbox`
+struct InboxActor<Msg> {
+    queue: Arc<InboxQueue<Msg>>,
+}
+
+impl<Msg> InboxActor<Msg>
+    where Msg: Message
+{
+    fn new(queue: Arc<InboxQueue<Msg>>) -> Self {
+        InboxActor {
+            queue
+        }
+    }
+}
+
+impl<Msg> Actor for InboxActor<Msg>
+    where Msg: Message
+{
+    type Msg = Msg;
+
+    fn recv(&mut self,
+            _: &Context<Self::Msg>,
+            msg: Self::Msg,
+            _: Sender) {
+        self.queue.push(msg);
+    }
+}
@@ -1,12 +1,17 @@
+use std::time::Duration;
+
 use log;
-use log::{info, Level};
+use log::{info, warn, Level};
 use config::Config;
+use regex::Regex;
 
 use crate::{
     actor::{
-        ActorRef, Tell, BoxActorProd, Props, Actor,
-        Context, BasicActorRef,ChannelMsg, All, DeadLetter, Subscribe
-    }
+        ActorRef, ActorReference, Tell, BoxActorProd, Props, Actor,
+        Context, BasicActorRef,ChannelMsg, All, DeadLetter, Subscribe,
+        selection::glob_to_regex
+    },
+    system::Timer
 };
 
 pub type LogActor = Box<dyn Actor<Msg=LogEntry> + Send>;
@@ -132,26 +137,92 @@ impl<'a> From<&'a Config> for LoggerConfig {
 // pub type DLActor = Box<dyn Actor<Msg=DeadLetter, Evt=()> + Send>;
 
 /// Simple actor that subscribes to the dead letters channel and logs using the default logger
+///
+/// At most `dead_letters.max_logged` dead letters are logged each
+/// `dead_letters.interval_millis`. Dead letters over the limit are counted
+/// and summarized at the end of the interval.
+///
+/// Dead letters are never logged if the recipient path matches one of
+/// `dead_letters.suppress_paths` or the message type one of
+/// `dead_letters.expected_types`.
 pub struct DeadLetterLogger {
     dl_chan: ActorRef<ChannelMsg<DeadLetter>>,
+    cfg: DeadLetterLoggerConfig,
+    logged: u32,
+    suppressed: u32,
+    interval_started: bool,
 }
 
 impl DeadLetterLogger {
-    fn new(dl_chan: ActorRef<ChannelMsg<DeadLetter>>) -> Self {
+    fn new((dl_chan, cfg): (ActorRef<ChannelMsg<DeadLetter>>, DeadLetterLoggerConfig)) -> Self {
         DeadLetterLogger {
-            dl_chan
+            dl_chan,
+            cfg,
+            logged: 0,
+            suppressed: 0,
+            interval_started: false
         }
     }
 
-    pub fn props(dl_chan: &ActorRef<ChannelMsg<DeadLetter>>)
+    pub fn props(dl_chan: &ActorRef<ChannelMsg<DeadLetter>>,
+                    cfg: DeadLetterLoggerConfig)
                     -> BoxActorProd<DeadLetterLogger> {
         
-        Props::new_args(DeadLetterLogger::new, dl_chan.clone())
+        Props::new_args(DeadLetterLogger::new, (dl_chan.clone(), cfg))
+    }
+
+    fn is_expected(&self, dl: &DeadLetter) -> bool {
+        let path = dl.recipient.path().to_string();
+
+        self.cfg.suppress_paths.iter().any(|p| p.is_match(&path)) ||
+            self.cfg.expected_types.iter().any(|t| {
+                dl.msg_type == t || dl.msg_type.ends_with(&format!("::{}", t))
+            })
+    }
+
+    fn log(&mut self, ctx: &Context<DeadLetterLoggerMsg>, dl: DeadLetter) {
+        if self.is_expected(&dl) {
+            return;
+        }
+
+        if self.cfg.max_logged == 0 {
+            log_dead_letter(&dl);
+            return;
+        }
+
+        if !self.interval_started {
+            self.interval_started = true;
+            ctx.schedule_once(self.cfg.interval,
+                            ctx.myself(),
+                            None,
+                            DeadLetterLoggerMsg::EndInterval);
+        }
+
+        if self.logged < self.cfg.max_logged {
+            self.logged += 1;
+            log_dead_letter(&dl);
+        } else {
+            self.suppressed += 1;
+        }
+    }
+
+    fn end_interval(&mut self) {
+        if self.suppressed > 0 {
+            info!("DeadLetter: {} more dead letters were suppressed", self.suppressed);
+        }
+
+        self.logged = 0;
+        self.suppressed = 0;
+        self.interval_started = false;
     }
 }
 
+fn log_dead_letter(dl: &DeadLetter) {
+    info!("DeadLetter: {:?} => {:?} ({:?}, {:?})", dl.sender, dl.recipient, dl.msg, dl.reason)
+}
+
 impl Actor for DeadLetterLogger {
-    type Msg = DeadLetter;
+    type Msg = DeadLetterLoggerMsg;
 
     fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
         let sub = Box::new(ctx.myself());
@@ -159,9 +230,56 @@ impl Actor for DeadLetterLogger {
     }
 
     fn recv(&mut self,
-                _: &Context<Self::Msg>,
+                ctx: &Context<Self::Msg>,
                 msg: Self::Msg,
                 _: Option<BasicActorRef>) {
-        info!("DeadLetter: {:?} => {:?} ({:?})", msg.sender, msg.recipient, msg.msg)
+        match msg {
+            DeadLetterLoggerMsg::DeadLetter(dl) => self.log(ctx, dl),
+            DeadLetterLoggerMsg::EndInterval => self.end_interval()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum DeadLetterLoggerMsg {
+    DeadLetter(DeadLetter),
+    EndInterval,
+}
+
+impl From<DeadLetter> for DeadLetterLoggerMsg {
+    fn from(dl: DeadLetter) -> Self {
+        DeadLetterLoggerMsg::DeadLetter(dl)
+    }
+}
+
+#[derive(Clone)]
+pub struct DeadLetterLoggerConfig {
+    max_logged: u32,
+    interval: Duration,
+    suppress_paths: Vec<Regex>,
+    expected_types: Vec<String>,
+}
+
+impl<'a> From<&'a Config> for DeadLetterLoggerConfig {
+    fn from(config: &Config) -> Self {
+        DeadLetterLoggerConfig {
+            max_logged: config.get_int("dead_letters.max_logged").unwrap() as u32,
+            interval: Duration::from_millis(config.get_int("dead_letters.interval_millis").unwrap() as u64),
+            suppress_paths: config.get_array("dead_letters.suppress_paths")
+                .unwrap_or(vec![])
+                .into_iter()
+                .filter_map(|p| {
+                    let p = p.to_string();
+                    Regex::new(&glob_to_regex(&p))
+                        .map_err(|_| warn!("Ignoring invalid dead_letters.suppress_paths entry: {}", p))
+                        .ok()
+                })
+                .collect(),
+            expected_types: config.get_array("dead_letters.expected_types")
+                .unwrap_or(vec![])
+                .into_iter()
+                .map(|t| t.to_string())
+                .collect(),
+        }
     }
 }
@@ -1,3 +1,5 @@
+pub(crate) mod event_stream;
+pub(crate) mod inbox;
 pub(crate) mod logger;
 pub(crate) mod system;
 pub(crate) mod timer;
@@ -7,10 +9,12 @@ use std::{
     error::Error
 };
 
-use crate::actor::BasicActorRef;
+use crate::actor::{ActorUri, BasicActorRef, Strategy};
 
 // Public riker::system API (plus the pub data types in this file)
 pub use self::{
+    event_stream::EventStream,
+    inbox::Inbox,
     system::{ActorSystem, SystemBuilder, Run},
     timer::{Timer, BasicTimer},
     logger::LogEntry
@@ -48,6 +52,24 @@ pub enum SystemEvent {
 
     /// An actor was started
     ActorTerminated(ActorTerminated),
+
+    /// A pool router was resized
+    RouterResized(RouterResized),
+
+    /// An actor panicked while processing a message
+    ActorFailed(ActorFailed),
+
+    /// A supervisor applied its strategy to a failed child
+    SupervisorDecision(SupervisorDecision),
+
+    /// An actor panicked while starting or restarting
+    ActorFailedToStart(ActorFailedToStart),
+
+    /// A message was rejected because an actor's mailbox is full
+    MailboxOverflow(MailboxOverflow),
+
+    /// The system is shutting down
+    SystemShutdown(SystemShutdown),
 }
 
 impl Into<SystemMsg> for SystemEvent {
@@ -71,6 +93,56 @@ pub struct ActorTerminated {
     pub actor: BasicActorRef,
 }
 
+#[derive(Clone, Debug)]
+pub struct RouterResized {
+    pub router: BasicActorRef,
+    pub previous: usize,
+    pub size: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct ActorFailed {
+    pub actor: BasicActorRef,
+    /// The panic message
+    pub reason: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct SupervisorDecision {
+    pub supervisor: BasicActorRef,
+    /// The failed child
+    pub actor: BasicActorRef,
+    pub strategy: Strategy,
+}
+
+/// The actor doesn't exist when it fails to start for the first time,
+/// so only its URI is available
+#[derive(Clone, Debug)]
+pub struct ActorFailedToStart {
+    pub uri: ActorUri,
+    /// The panic message
+    pub reason: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct MailboxOverflow {
+    pub actor: BasicActorRef,
+}
+
+#[derive(Clone, Debug)]
+pub struct SystemShutdown {
+    pub phase: ShutdownPhase,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShutdownPhase {
+    /// Shutdown has started and user actors are being stopped
+    Started,
+
+    /// All user actors have stopped
+    Completed,
+}
+
 impl Into<SystemEvent> for ActorCreated {
     fn into(self) -> SystemEvent {
         SystemEvent::ActorCreated(self)
@@ -89,6 +161,42 @@ impl Into<SystemEvent> for ActorTerminated {
     }
 }
 
+impl Into<SystemEvent> for RouterResized {
+    fn into(self) -> SystemEvent {
+        SystemEvent::RouterResized(self)
+    }
+}
+
+impl Into<SystemEvent> for ActorFailed {
+    fn into(self) -> SystemEvent {
+        SystemEvent::ActorFailed(self)
+    }
+}
+
+impl Into<SystemEvent> for SupervisorDecision {
+    fn into(self) -> SystemEvent {
+        SystemEvent::SupervisorDecision(self)
+    }
+}
+
+impl Into<SystemEvent> for ActorFailedToStart {
+    fn into(self) -> SystemEvent {
+        SystemEvent::ActorFailedToStart(self)
+    }
+}
+
+impl Into<SystemEvent> for MailboxOverflow {
+    fn into(self) -> SystemEvent {
+        SystemEvent::MailboxOverflow(self)
+    }
+}
+
+impl Into<SystemEvent> for SystemShutdown {
+    fn into(self) -> SystemEvent {
+        SystemEvent::SystemShutdown(self)
+    }
+}
+
 impl Into<SystemMsg> for ActorCreated {
     fn into(self) -> SystemMsg {
         SystemMsg::Event(SystemEvent::ActorCreated(self))
@@ -107,11 +215,53 @@ impl Into<SystemMsg> for ActorTerminated {
     }
 }
 
+impl Into<SystemMsg> for RouterResized {
+    fn into(self) -> SystemMsg {
+        SystemMsg::Event(SystemEvent::RouterResized(self))
+    }
+}
+
+impl Into<SystemMsg> for ActorFailed {
+    fn into(self) -> SystemMsg {
+        SystemMsg::Event(SystemEvent::ActorFailed(self))
+    }
+}
+
+impl Into<SystemMsg> for SupervisorDecision {
+    fn into(self) -> SystemMsg {
+        SystemMsg::Event(SystemEvent::SupervisorDecision(self))
+    }
+}
+
+impl Into<SystemMsg> for ActorFailedToStart {
+    fn into(self) -> SystemMsg {
+        SystemMsg::Event(SystemEvent::ActorFailedToStart(self))
+    }
+}
+
+impl Into<SystemMsg> for MailboxOverflow {
+    fn into(self) -> SystemMsg {
+        SystemMsg::Event(SystemEvent::MailboxOverflow(self))
+    }
+}
+
+impl Into<SystemMsg> for SystemShutdown {
+    fn into(self) -> SystemMsg {
+        SystemMsg::Event(SystemEvent::SystemShutdown(self))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum SystemEventType {
     ActorTerminated,
     ActorRestarted,
     ActorCreated,
+    RouterResized,
+    ActorFailed,
+    SupervisorDecision,
+    ActorFailedToStart,
+    MailboxOverflow,
+    SystemShutdown,
 }
 
 pub enum SystemError {
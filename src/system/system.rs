@@ -1,7 +1,6 @@
 use std::{
     fmt,
     str::FromStr,
-    ops::Deref,
     time::{Duration, SystemTime},
     sync::{Arc, Mutex}
 };
@@ -22,9 +21,15 @@ use log::{debug, Level};
 use crate::{
     Message, AnyMessage,
     actor::*,
-    system::{SystemMsg, SystemCmd, SystemEvent, SystemError, ActorTerminated},
+    actor::channel::EventSub,
+    system::{
+        SystemMsg, SystemCmd, SystemEvent, SystemError, ActorTerminated,
+        SystemShutdown, ShutdownPhase
+    },
     system::timer::*,
     system::logger::*,
+    system::event_stream::{EventStream, EventStreamActor},
+    system::inbox::Inbox,
     load_config,
     kernel::provider::{Provider, create_root},
     validate::{validate_name, InvalidPath}
@@ -187,7 +192,8 @@ impl ActorSystem {
         sys.sys_channels = Some(sys_channels(&prov, &sys)?);
         
         // 6. start dead letter logger
-        let props = DeadLetterLogger::props(sys.dead_letters());
+        let props = DeadLetterLogger::props(sys.dead_letters(),
+                                            DeadLetterLoggerConfig::from(&cfg));
         let _dl_logger = sys_actor_of(&prov, &sys, props, "dl_logger")?;
 
         sys.complete_start();
@@ -258,8 +264,7 @@ impl ActorSystem {
     }
 
     /// Returns the system root's actor reference 
-    #[allow(dead_code)]
-    fn root(&self) -> &BasicActorRef {
+    pub(crate) fn root(&self) -> &BasicActorRef {
         &self.sys_actors.as_ref().unwrap().root
     }
 
@@ -278,6 +283,37 @@ impl ActorSystem {
         &self.sys_actors.as_ref().unwrap().temp
     }
 
+    /// Returns the actor at the given absolute path, e.g. `/user/my-actor`
+    ///
+    /// Actors are indexed by path when created, so unlike `select` this
+    /// doesn't traverse the actor hierarchy.
+    pub fn lookup(&self, path: &str) -> Option<BasicActorRef> {
+        self.provider.lookup(&ActorPath::new(path))
+    }
+
+    /// Returns the actor at the given address, if the address is local
+    ///
+    /// An address is local if its system name and host match this
+    /// system and it has no port, since a local system doesn't listen
+    /// on one. If the address includes a uid, the actor is only returned
+    /// if it is the same incarnation.
+    ///
+    /// Returns `None` if the address is invalid, not local or no
+    /// matching actor exists.
+    pub fn resolve_address(&self, addr: &str) -> Option<BasicActorRef> {
+        let addr = addr.parse::<ActorAddress>().ok()?;
+
+        if addr.system != self.name() || addr.host != *self.host() || addr.port.is_some() {
+            return None;
+        }
+
+        let actor = self.lookup(&addr.path)?;
+        match addr.uid {
+            Some(uid) if uid != actor.uri().uid => None,
+            _ => Some(actor)
+        }
+    }
+
     /// Returns a reference to the system events channel
     pub fn sys_events(&self) -> &ActorRef<ChannelMsg<SystemEvent>> {
         &self.sys_channels.as_ref().unwrap().sys_events
@@ -288,9 +324,25 @@ impl ActorSystem {
         &self.sys_channels.as_ref().unwrap().dead_letters
     }
 
+    /// Returns the system wide event stream
+    pub fn event_stream(&self) -> &EventStream {
+        &self.sys_channels.as_ref().unwrap().event_stream
+    }
+
+    /// Creates an `Inbox` to receive messages outside of actors
+    /// 
+    /// Returns an error if the inbox's actor can't be created, e.g.
+    /// while the system is shutting down.
+    pub fn inbox<Msg: Message>(&self) -> Result<Inbox<Msg>, CreateError> {
+        Inbox::new(self)
+    }
+
     pub fn publish_event(&self, evt: SystemEvent) {
-        let topic = Topic::from(&evt);
-        self.sys_events().tell(Publish {topic, msg: evt}, None);
+        // events raised while the system is starting are not published
+        if let Some(chans) = self.sys_channels.as_ref() {
+            let topic = Topic::from(&evt);
+            chans.sys_events.tell(Publish {topic, msg: evt}, None);
+        }
     }
 
     /// Returns the `Config` used by the system
@@ -327,6 +379,8 @@ impl ActorSystem {
         let (tx, rx) = oneshot::channel::<()>();
         let tx = Arc::new(Mutex::new(Some(tx)));
 
+        self.publish_event(SystemShutdown { phase: ShutdownPhase::Started }.into());
+
         let props = Props::new_args(ShutdownActor::new, tx);
         self.tmp_actor_of(props).unwrap();
 
@@ -373,19 +427,15 @@ impl ActorSelectionFactory for ActorSystem {
     fn select(&self, path: &str)
                 -> Result<ActorSelection, InvalidPath> {
 
-        let anchor = self.user_root();
-        let (anchor, path_str) = if path.starts_with("/") {
-            let anchor = self.user_root();
-            let anchor_path = format!("{}/", anchor.path().deref().clone());
-            let path = path.to_string().replace(&anchor_path, "");
-            
-            (anchor, path)
+        let (anchor, path_str) = if path.starts_with('/') {
+            // absolute paths are anchored at root, e.g. /user/a, /system/b
+            (self.root(), path.trim_start_matches('/').to_string())
         } else {
-            (anchor, path.to_string())
+            (self.user_root(), path.to_string())
         };
 
         ActorSelection::new(anchor.clone(),
-                            // self.dead_letters(),
+                            self.dead_letters(),
                             path_str)
     }
 }
@@ -536,26 +586,45 @@ fn sys_channels(prov: &Provider,
     let props = Props::new(Channel::<DeadLetter>::new);
     let dead_letters = sys_actor_of(prov, sys, props, "dead_letters")?;
 
-    // subscribe the dead_letters channel to actor terminated events
-    // so that any future subscribed actors that terminate are automatically
-    // unsubscribed from the dead_letters channel
-    // let msg = ChannelMsg::Subscribe(SysTopic::ActorTerminated.into(), dl.clone());
-    // es.tell(msg, None);
+    let props = Props::new(EventStreamActor::new);
+    let event_stream = sys_actor_of(prov, sys, props, "event_stream")?;
+
+    // subscribe the system channels and the event stream to actor terminated
+    // events so that any future subscribed actors that terminate are
+    // automatically unsubscribed
+    let actors = [
+        BasicActorRef::from(sys_events.clone()),
+        dead_letters.clone().into(),
+        event_stream.clone().into()
+    ];
+
+    for chan in &actors {
+        let sub = Subscribe {
+            topic: SysTopic::ActorTerminated.into(),
+            actor: Box::new(EventSub(chan.clone()))
+        };
+        sys_events.tell(sub, None);
+    }
 
     Ok(SysChannels {
         sys_events,
-        dead_letters
+        dead_letters,
+        event_stream: EventStream::new(event_stream)
     })
 }
 
 pub struct SystemSettings {
     pub msg_process_limit: u32,
+    pub mailbox_capacity: Option<usize>,
 }
 
 impl<'a> From<&'a Config> for SystemSettings {
     fn from(config: &Config) -> Self {
+        let capacity = config.get_int("mailbox.capacity").unwrap();
+
         SystemSettings {
-            msg_process_limit: config.get_int("mailbox.msg_process_limit").unwrap() as u32
+            msg_process_limit: config.get_int("mailbox.msg_process_limit").unwrap() as u32,
+            mailbox_capacity: if capacity > 0 { Some(capacity as usize) } else { None }
         }
     }
 }
@@ -598,6 +667,7 @@ pub struct SysActors {
 pub struct SysChannels {
     pub sys_events: ActorRef<ChannelMsg<SystemEvent>>,
     pub dead_letters: ActorRef<DLChannelMsg>,
+    pub event_stream: EventStream,
 }
 
 pub type Shutdown = oneshot::Receiver<()>;
@@ -662,6 +732,8 @@ impl Receive<ActorTerminated> for ShutdownActor {
                 _sender: Option<BasicActorRef>) {
 
         if &msg.actor == ctx.system.user_root() {
+            ctx.system.publish_event(SystemShutdown { phase: ShutdownPhase::Completed }.into());
+
             if let Ok(ref mut tx) = self.tx.lock() {
                 if let Some(tx) = tx.take() {
                     tx.send(()).unwrap();
@@ -34,7 +34,7 @@ impl fmt::Debug for InvalidName {
 }
 
 pub fn validate_path(path: &str) -> Result<(), InvalidPath> {
-    let rgx = Regex::new(r"^[a-zA-Z0-9/*._-]+$").unwrap();
+    let rgx = Regex::new(r"^([a-zA-Z0-9/*?\]._-]|\[!?)+$").unwrap();
     if !rgx.is_match(path) {
         Err(InvalidPath { path: path.into() })
     } else {
@@ -46,9 +46,15 @@ pub struct InvalidPath {
     path: String
 }
 
+impl InvalidPath {
+    pub(crate) fn new(path: &str) -> Self {
+        InvalidPath { path: path.into() }
+    }
+}
+
 impl Error for InvalidPath {
     fn description(&self) -> &str {
-        "Invalid path. Must contain only a-Z, 0-9, /, _, .., - or *"
+        "Invalid path. Must contain only a-Z, 0-9, /, _, .., -, or the glob patterns *, **, ?, [] and [!]"
     }
 }
 
@@ -64,4 +70,30 @@ impl fmt::Debug for InvalidPath {
     }
 }
 
+pub struct InvalidAddress {
+    addr: String
+}
+
+impl InvalidAddress {
+    pub(crate) fn new(addr: &str) -> Self {
+        InvalidAddress { addr: addr.into() }
+    }
+}
+
+impl Error for InvalidAddress {
+    fn description(&self) -> &str {
+        "Invalid actor address. Must be in the form riker://system@host[:port]/path[#uid]"
+    }
+}
+
+impl fmt::Display for InvalidAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&format!("\"{}\". {}", self.addr, self.description()))
+    }
+}
 
+impl fmt::Debug for InvalidAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&format!("\"{}\". {}", self.addr, self.description()))
+    }
+}
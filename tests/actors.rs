@@ -1,6 +1,10 @@
 #[macro_use]
 extern crate riker_testkit;
 
+use std::time::Duration;
+
+use futures::{executor::block_on, stream, StreamExt};
+
 use riker::actors::*;
 
 use riker_testkit::probe::{Probe, ProbeReceive};
@@ -109,7 +113,7 @@ fn actor_try_tell() {
     actor.try_tell(CounterMsg::TestProbe(TestProbe(probe)), None).unwrap();
 
     assert!(actor.try_tell(CounterMsg::Add(Add), None).is_ok());
-    assert!(actor.try_tell("invalid-type".to_string(), None).is_err());
+    assert_eq!(actor.try_tell("invalid-type".to_string(), None), Err(TellError::UnsupportedType));
 
     for _ in 0..1_000_000 {
         actor.try_tell(CounterMsg::Add(Add), None).unwrap();
@@ -118,6 +122,30 @@ fn actor_try_tell() {
     p_assert_eq!(listen, ());
 }
 
+#[test]
+fn actor_typed() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Props::new(Counter::actor);
+    let actor = sys.actor_of(props, "me").unwrap();
+    let basic: BasicActorRef = actor.clone().into();
+
+    assert!(basic.typed::<String>().is_none());
+    assert!(basic.typed::<SystemMsg>().is_none());
+
+    let typed = basic.typed::<CounterMsg>().unwrap();
+    assert!(typed == actor);
+
+    let (probe, listen) = probe();
+    typed.tell(TestProbe(probe), None);
+
+    for _ in 0..1_000_000 {
+        typed.tell(Add, None);
+    }
+
+    p_assert_eq!(listen, ());
+}
+
 struct Parent {
     probe: Option<TestProbe>,
 }
@@ -195,3 +223,295 @@ fn actor_stop() {
     system.stop(&parent);
     p_assert_eq!(listen, ());
 }
+
+#[derive(Clone, Debug)]
+pub struct Tag(ChannelProbe<(), String>, String);
+
+#[derive(Clone, Debug)]
+pub struct Greet {
+    name: String,
+    reply_to: BoxedTell<String>,
+}
+
+struct Greeter;
+
+impl Actor for Greeter {
+    type Msg = Greet;
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        msg.reply_to.tell(format!("hello {}", msg.name), None);
+    }
+}
+
+struct Echo;
+
+impl Actor for Echo {
+    type Msg = Tag;
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        msg.0.event(msg.1);
+    }
+}
+
+struct DeadLetterSub {
+    probe: ChannelProbe<(), String>,
+}
+
+impl Actor for DeadLetterSub {
+    type Msg = DeadLetter;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Box::new(ctx.myself());
+        ctx.system
+            .dead_letters()
+            .tell(Subscribe { actor: sub, topic: "*".into() }, None);
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(format!("dead letter: {}", msg.recipient.path()));
+    }
+}
+
+#[test]
+fn actor_adapted_reply_to() {
+    let system = ActorSystem::new().unwrap();
+
+    let (probe, listen) = probe();
+
+    let echo = system.actor_of(Props::new(|| Echo), "echo").unwrap();
+    let greeter = system.actor_of(Props::new(|| Greeter), "greeter").unwrap();
+
+    // the greeter replies with a `String` that is converted to a `Tag`
+    let reply_to = echo.adapt(move |s: String| Tag(probe.clone(), s));
+    greeter.tell(Greet { name: "riker".into(), reply_to }, None);
+
+    p_assert_eq!(listen, "hello riker".to_string());
+}
+
+#[test]
+fn actor_stale_ref() {
+    let system = ActorSystem::new().unwrap();
+
+    let (probe, listen) = probe();
+
+    let props = Props::new_args(|probe| DeadLetterSub { probe }, probe.clone());
+    system.actor_of(props, "dl-sub").unwrap();
+
+    let old = system.actor_of(Props::new(|| Echo), "echo").unwrap();
+    system.stop(&old);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    // a new incarnation of the actor at the same path
+    let new = system.actor_of(Props::new(|| Echo), "echo").unwrap();
+
+    let old_ref: BasicActorRef = old.clone().into();
+    let new_ref: BasicActorRef = new.clone().into();
+    assert_eq!(old_ref.path(), new_ref.path());
+    assert_ne!(old_ref, new_ref);
+    assert_ne!(old, new);
+
+    // messages sent to the terminated incarnation go to dead letters
+    old.tell(Tag(probe.clone(), "old".into()), None);
+    p_assert_eq!(listen, "dead letter: /user/echo".to_string());
+
+    new.tell(Tag(probe.clone(), "new".into()), None);
+    p_assert_eq!(listen, "new".to_string());
+
+    // try_send returns the message instead
+    let err = old.try_send(Tag(probe.clone(), "old".into()), None).unwrap_err();
+    assert_eq!(err.reason, TellError::Terminated);
+    assert_eq!(err.msg.1, "old");
+
+    assert_eq!(old_ref.try_tell(Tag(probe, "old".into()), None), Err(TellError::Terminated));
+    p_assert_eq!(listen, "dead letter: /user/echo".to_string());
+}
+
+// Retries dead letters at a fallback actor
+struct Fallback {
+    probe: ChannelProbe<(), String>,
+    fallback: ActorRef<Tag>,
+}
+
+impl Actor for Fallback {
+    type Msg = DeadLetter;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Box::new(ctx.myself());
+        ctx.system
+            .dead_letters()
+            .tell(Subscribe { actor: sub, topic: "*".into() }, None);
+        self.probe.event("subscribed".into());
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(format!("{:?}", msg.reason));
+
+        if let Some(tag) = msg.payload::<Tag>() {
+            self.fallback.tell(tag, None);
+        }
+
+        if let Some(n) = msg.payload::<u32>() {
+            self.probe.event(format!("u32: {}", n));
+        }
+    }
+}
+
+#[test]
+fn actor_dead_letter_payload() {
+    let system = ActorSystem::new().unwrap();
+
+    let (probe, listen) = probe();
+
+    let fallback = system.actor_of(Props::new(|| Echo), "fallback").unwrap();
+    let props = Props::new_args(|(probe, fallback)| Fallback { probe, fallback },
+                                (probe.clone(), fallback));
+    system.actor_of(props, "dl-sub").unwrap();
+    p_assert_eq!(listen, "subscribed".to_string());
+
+    let echo = system.actor_of(Props::new(|| Echo), "echo").unwrap();
+    let basic: BasicActorRef = echo.clone().into();
+
+    // unsupported types are sent to dead letters with the original message
+    assert_eq!(basic.try_tell(7u32, None), Err(TellError::UnsupportedType));
+    p_assert_eq!(listen, "UnsupportedType".to_string());
+    p_assert_eq!(listen, "u32: 7".to_string());
+
+    // a selection drops messages its actors don't support
+    system.select("/user/echo").unwrap().try_tell(8u32, None);
+
+    system.stop(&echo);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    // dead letters carry the original message
+    assert_eq!(basic.try_tell(7u32, None), Err(TellError::Terminated));
+    p_assert_eq!(listen, "Terminated".to_string());
+    p_assert_eq!(listen, "u32: 7".to_string());
+
+    // the dead letter subscriber retries at the fallback
+    echo.tell(Tag(probe, "retried".into()), None);
+    p_assert_eq!(listen, "Terminated".to_string());
+    p_assert_eq!(listen, "retried".to_string());
+}
+
+struct Sleeper;
+
+impl Actor for Sleeper {
+    type Msg = u32;
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                _msg: Self::Msg,
+                _sender: Sender) {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn actor_mailbox_full() {
+    let mut cfg = riker::load_config();
+    cfg.set("mailbox.capacity", 2).unwrap();
+    let sys = SystemBuilder::new().name("bounded").cfg(cfg).create().unwrap();
+
+    let actor = sys.actor_of(Props::new(|| Sleeper), "sleeper").unwrap();
+
+    // the actor may take the first message before the mailbox fills up
+    let err = (0..4)
+        .map(|i| actor.try_send(i, None))
+        .find_map(Result::err)
+        .unwrap();
+
+    assert_eq!(err.reason, TellError::MailboxFull);
+    assert!(err.msg >= 2);
+
+    // the actor may have taken another message in the meantime
+    let actor: BasicActorRef = actor.into();
+    let err = (0..2)
+        .map(|_| actor.try_tell(10u32, None))
+        .find_map(Result::err);
+
+    assert_eq!(err, Some(TellError::MailboxFull));
+}
+
+// Slowly echoes messages to a probe
+struct SlowEcho {
+    probe: ChannelProbe<(), u32>,
+}
+
+impl Actor for SlowEcho {
+    type Msg = u32;
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        self.probe.event(msg);
+    }
+}
+
+#[test]
+fn actor_sink_backpressure() {
+    let mut cfg = riker::load_config();
+    cfg.set("mailbox.capacity", 2).unwrap();
+    let sys = SystemBuilder::new().name("bounded").cfg(cfg).create().unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|probe| SlowEcho { probe }, probe);
+    let actor = sys.actor_of(props, "slow-echo").unwrap();
+
+    // the sink waits for the mailbox to have space, so nothing is lost
+    let msgs = stream::iter(0..20u32).map(Ok);
+    block_on(msgs.forward(actor.sink())).unwrap();
+
+    for i in 0..20u32 {
+        p_assert_eq!(listen, i);
+    }
+}
+
+// Replies to the sender with the message it received
+struct Reply;
+
+impl Actor for Reply {
+    type Msg = String;
+
+    fn recv(&mut self,
+                ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                sender: Sender) {
+        sender.unwrap().try_tell(msg, Some(ctx.myself().into())).unwrap();
+    }
+}
+
+#[test]
+fn actor_inbox() {
+    let sys = ActorSystem::new().unwrap();
+    let reply = sys.actor_of(Props::new(|| Reply), "reply").unwrap();
+
+    let inbox = sys.inbox::<String>().unwrap();
+    assert!(inbox.actor().path().to_string().starts_with("/temp/"));
+
+    reply.tell("hello".to_string(), inbox.sender());
+    assert_eq!(inbox.receive(Duration::from_secs(3)), Some("hello".to_string()));
+    assert_eq!(inbox.receive(Duration::from_millis(50)), None);
+
+    reply.tell("world".to_string(), inbox.sender());
+    assert_eq!(block_on(inbox.next()), "world".to_string());
+
+    // the inbox's actor is stopped when the inbox is dropped
+    let actor: BasicActorRef = inbox.actor().clone().into();
+    drop(inbox);
+    std::thread::sleep(Duration::from_millis(500));
+    assert_eq!(actor.try_tell("hello".to_string(), None), Err(TellError::Terminated));
+}
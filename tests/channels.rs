@@ -1,10 +1,14 @@
 #[macro_use]
 extern crate riker_testkit;
 
+use std::sync::Arc;
+
+use futures::{executor::block_on, StreamExt};
+
 use riker::actors::*;
 
 use riker_testkit::probe::{Probe, ProbeReceive};
-use riker_testkit::probe::channel::{probe, ChannelProbe};
+use riker_testkit::probe::channel::{probe, ChannelProbe, ChannelProbeReceive};
 
 #[derive(Clone, Debug)]
 pub struct TestProbe(ChannelProbe<(), ()>);
@@ -99,6 +103,195 @@ fn channel_publish() {
     p_assert_eq!(listen, ());
 }
 
+#[test]
+fn channel_publish_adapted() {
+    let sys = ActorSystem::new().unwrap();
+
+    let chan: ChannelRef<SomeMessage> = channel("my-chan", &sys).unwrap();
+    let sub = sys.actor_of(Subscriber::props(chan, "my-topic".into()), "sub-actor").unwrap();
+
+    let (probe, listen) = probe();
+    sub.tell(TestProbe(probe), None);
+
+    // wait for the probe to arrive at the actor before subscribing
+    listen.recv();
+
+    // subscribe to a channel of another message type using an adapted ref
+    let str_chan: ChannelRef<String> = channel("str-chan", &sys).unwrap();
+    let adapted = sub.adapt(|_: String| SomeMessage.into());
+    str_chan.tell(Subscribe { actor: adapted, topic: "str-topic".into() }, None);
+
+    str_chan.tell(Publish { msg: "hello".to_string(), topic: "str-topic".into() }, None);
+
+    p_assert_eq!(listen, ());
+}
+
+// Echoes published topic names to a probe
+struct TopicSub {
+    probe: ChannelProbe<(), String>,
+    chan: ChannelRef<String>,
+    topic: Topic,
+}
+
+impl Actor for TopicSub {
+    type Msg = String;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Box::new(ctx.myself());
+        self.chan.tell(Subscribe { actor: sub, topic: self.topic.clone() }, None);
+        self.probe.event("subscribed".into());
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(msg);
+    }
+}
+
+fn publish_topics(pattern: &str, topics: &[&str]) -> (ActorSystem, ChannelProbeReceive<String>) {
+    let sys = ActorSystem::new().unwrap();
+    let chan: ChannelRef<String> = channel("my-chan", &sys).unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|(probe, chan, topic)| TopicSub { probe, chan, topic },
+                                (probe, chan.clone(), Topic::from(pattern)));
+    sys.actor_of(props, "sub-actor").unwrap();
+    listen.recv();
+
+    for topic in topics {
+        chan.tell(Publish { msg: topic.to_string(), topic: (*topic).into() }, None);
+    }
+
+    (sys, listen)
+}
+
+#[test]
+fn channel_publish_wildcards() {
+    let (_sys, listen) = publish_topics("orders.*.created",
+                                &["orders.eu.created", "orders.eu.deleted", "orders.created",
+                                    "orders.eu.x.created", "orders.us.created"]);
+    p_assert_eq!(listen, "orders.eu.created".to_string());
+    p_assert_eq!(listen, "orders.us.created".to_string());
+
+    let (_sys, listen) = publish_topics("orders.#",
+                                &["customers.eu", "orders", "orders.eu.created", "orders.us"]);
+    p_assert_eq!(listen, "orders".to_string());
+    p_assert_eq!(listen, "orders.eu.created".to_string());
+    p_assert_eq!(listen, "orders.us".to_string());
+
+    let (_sys, listen) = publish_topics("#.created",
+                                &["orders.eu.deleted", "created", "orders.eu.created"]);
+    p_assert_eq!(listen, "created".to_string());
+    p_assert_eq!(listen, "orders.eu.created".to_string());
+
+    let (_sys, listen) = publish_topics("*",
+                                &["orders", "orders.eu.created"]);
+    p_assert_eq!(listen, "orders".to_string());
+    p_assert_eq!(listen, "orders.eu.created".to_string());
+}
+
+#[test]
+fn channel_retained() {
+    let sys = ActorSystem::new().unwrap();
+
+    let config = ChannelConfig::new().retain(2);
+    let chan: ChannelRef<String> = channel_with_config("my-chan", &sys, config).unwrap();
+
+    for msg in &["a", "b", "c"] {
+        chan.tell(Publish { msg: msg.to_string(), topic: "status.a".into() }, None);
+    }
+    chan.tell(Publish { msg: "other".into(), topic: "other".into() }, None);
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|(probe, chan, topic)| TopicSub { probe, chan, topic },
+                                (probe, chan.clone(), Topic::from("status.*")));
+    sys.actor_of(props, "sub-actor").unwrap();
+    p_assert_eq!(listen, "subscribed".to_string());
+
+    // the last two messages are replayed on subscribing
+    p_assert_eq!(listen, "b".to_string());
+    p_assert_eq!(listen, "c".to_string());
+
+    chan.tell(Publish { msg: "d".into(), topic: "status.a".into() }, None);
+    p_assert_eq!(listen, "d".to_string());
+}
+
+#[test]
+fn channel_publish_filtered() {
+    let sys = ActorSystem::new().unwrap();
+    let chan: ChannelRef<String> = channel("my-chan", &sys).unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|(probe, chan, topic)| TopicSub { probe, chan, topic },
+                                (probe, chan.clone(), Topic::from("unused")));
+    let sub = sys.actor_of(props, "sub-actor").unwrap();
+    listen.recv();
+
+    let filter: Filter<String> = Arc::new(|msg: &String| msg.starts_with("keep"));
+    chan.tell(SubscribeFiltered { actor: Box::new(sub), topic: "my-topic".into(), filter }, None);
+
+    for msg in &["drop-1", "keep-1", "drop-2", "keep-2"] {
+        chan.tell(Publish { msg: msg.to_string(), topic: "my-topic".into() }, None);
+    }
+
+    p_assert_eq!(listen, "keep-1".to_string());
+    p_assert_eq!(listen, "keep-2".to_string());
+}
+
+// Echoes messages from a sharded channel with the address of the shared payload
+struct SharedSub {
+    probe: ChannelProbe<(), String>,
+    chan: ShardedChannelRef<String>,
+}
+
+impl Actor for SharedSub {
+    type Msg = Arc<String>;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Box::new(ctx.myself());
+        self.chan.tell(Subscribe { actor: sub, topic: "my-topic".into() }, None);
+        self.probe.event("subscribed".into());
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(format!("{} {:p}", msg, msg));
+    }
+}
+
+#[test]
+fn channel_sharded() {
+    let sys = ActorSystem::new().unwrap();
+
+    let config = ChannelConfig::new().shards(4).retain(1);
+    let chan: ShardedChannelRef<String> = sharded_channel("my-chan", &sys, config).unwrap();
+    chan.tell(Publish { msg: "retained".into(), topic: "my-topic".into() }, None);
+
+    let (probe, listen) = probe();
+    for i in 0..8 {
+        let props = Props::new_args(|(probe, chan)| SharedSub { probe, chan },
+                                    (probe.clone(), chan.clone()));
+        sys.actor_of(props, &format!("sub-actor-{}", i)).unwrap();
+    }
+
+    // each subscriber is subscribed and receives the retained message
+    let mut events: Vec<String> = (0..16).map(|_| listen.recv()).collect();
+    events.sort();
+    assert!(events[..8].iter().all(|e| e.starts_with("retained ")));
+    assert_eq!(events[8..], vec!["subscribed".to_string(); 8][..]);
+
+    chan.tell(Publish { msg: "published".into(), topic: "my-topic".into() }, None);
+
+    // all subscribers receive the same payload
+    let events: Vec<String> = (0..8).map(|_| listen.recv()).collect();
+    assert!(events[0].starts_with("published "));
+    assert!(events.iter().all(|e| *e == events[0]));
+}
+
 #[test]
 fn channel_publish_subscribe_all() {
     let sys = ActorSystem::new().unwrap();
@@ -269,6 +462,7 @@ impl Receive<SystemEvent> for EventSubscriber {
                     self.probe.as_ref().unwrap().0.event(())
                 }
             }
+            _ => {}
         }
     }
 }
@@ -388,3 +582,239 @@ fn channel_dead_letters() {
 
     p_assert_eq!(listen, ());
 }
+
+// Sends the message of each dead letter to a probe
+struct DeadLetterProbe {
+    probe: ChannelProbe<(), String>,
+}
+
+impl Actor for DeadLetterProbe {
+    type Msg = DeadLetter;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Box::new(ctx.myself());
+        ctx.system
+            .dead_letters()
+            .tell(Subscribe { actor: sub, topic: "*".into() }, None);
+        self.probe.event("subscribed".into());
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(msg.msg);
+    }
+}
+
+#[test]
+fn channel_unsubscribe_terminated() {
+    let sys = ActorSystem::new().unwrap();
+
+    let (dl_probe, dl_listen) = probe();
+    let props = Props::new_args(|probe| DeadLetterProbe { probe }, dl_probe);
+    sys.actor_of(props, "dl-probe").unwrap();
+    dl_listen.recv();
+
+    // a terminated dead letters subscriber would turn each dead letter into another
+    let (stale_probe, stale_listen) = probe();
+    let props = Props::new_args(|probe| DeadLetterProbe { probe }, stale_probe);
+    let stale = sys.actor_of(props, "dl-stale").unwrap();
+    stale_listen.recv();
+    sys.stop(&stale);
+
+    let chan: ChannelRef<String> = channel("my-chan", &sys).unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|(probe, chan, topic)| TopicSub { probe, chan, topic },
+                                (probe, chan.clone(), Topic::from("my-topic")));
+    let sub = sys.actor_of(props, "sub-actor").unwrap();
+    listen.recv();
+
+    sys.stop(&sub);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    // the terminated subscriber was removed, so this isn't a dead letter
+    chan.tell(Publish { msg: "published".into(), topic: "my-topic".into() }, None);
+
+    // subscribing the terminated actor again results in a dead letter
+    chan.tell(Subscribe { actor: Box::new(sub.clone()), topic: "marker".into() }, None);
+    chan.tell(Publish { msg: "marker".into(), topic: "marker".into() }, None);
+
+    p_assert_eq!(dl_listen, "\"marker\"".to_string());
+
+    // the terminated dead letters subscriber was removed, so nothing precedes this
+    sub.tell("end".to_string(), None);
+    p_assert_eq!(dl_listen, "\"end\"".to_string());
+}
+
+// Reports the `created-*` actors created while the wrapped actor is subscribed
+// to system events. System messages to terminated actors are dropped, so a stale
+// subscription would otherwise go unnoticed
+#[derive(Clone)]
+struct EventSpy {
+    actor: BasicActorRef,
+    probe: ChannelProbe<(), String>,
+}
+
+impl ActorReference for EventSpy {
+    fn name(&self) -> &str {
+        self.actor.name()
+    }
+
+    fn uri(&self) -> &ActorUri {
+        self.actor.uri()
+    }
+
+    fn path(&self) -> &ActorPath {
+        self.actor.path()
+    }
+
+    fn is_root(&self) -> bool {
+        self.actor.is_root()
+    }
+
+    fn parent(&self) -> BasicActorRef {
+        self.actor.parent()
+    }
+
+    fn user_root(&self) -> BasicActorRef {
+        self.actor.user_root()
+    }
+
+    fn has_children(&self) -> bool {
+        self.actor.has_children()
+    }
+
+    fn is_child(&self, actor: &BasicActorRef) -> bool {
+        self.actor.is_child(actor)
+    }
+
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = BasicActorRef> + 'a> {
+        self.actor.children()
+    }
+
+    fn sys_tell(&self, msg: SystemMsg) {
+        if let SystemMsg::Event(SystemEvent::ActorCreated(created)) = msg {
+            // events of the subscribers themselves may still be in flight
+            if !created.actor.name().starts_with("created-") {
+                return;
+            }
+            self.probe.event(format!("{}: {}", self.actor.name(), created.actor.name()));
+        }
+    }
+}
+
+impl Tell<SystemEvent> for EventSpy {
+    fn tell(&self, msg: SystemEvent, _sender: Sender) {
+        self.sys_tell(SystemMsg::Event(msg));
+    }
+
+    fn box_clone(&self) -> BoxedTell<SystemEvent> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn channel_unsubscribe_terminated_sys_events() {
+    let sys = ActorSystem::new().unwrap();
+
+    let (probe, listen) = probe();
+    let sub = sys.actor_of(Props::new(DumbActor::new), "events-sub").unwrap();
+    let live = sys.actor_of(Props::new(DumbActor::new), "events-live").unwrap();
+
+    for actor in vec![sub.clone(), live] {
+        let spy = EventSpy { actor: actor.into(), probe: probe.clone() };
+        sys.sys_events().tell(Subscribe { actor: Box::new(spy), topic: "actor.created".into() }, None);
+    }
+
+    sys.actor_of(Props::new(DumbActor::new), "created-1").unwrap();
+    let mut events = vec![listen.recv(), listen.recv()];
+    events.sort();
+    assert_eq!(events, vec!["events-live: created-1", "events-sub: created-1"]);
+
+    sys.stop(&sub);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    // only the live subscriber remains
+    sys.actor_of(Props::new(DumbActor::new), "created-2").unwrap();
+    p_assert_eq!(listen, "events-live: created-2".to_string());
+    sys.actor_of(Props::new(DumbActor::new), "created-3").unwrap();
+    p_assert_eq!(listen, "events-live: created-3".to_string());
+}
+
+// Subscribes to `String` messages published to the event stream
+struct StreamSub {
+    probe: ChannelProbe<(), String>,
+}
+
+impl Actor for StreamSub {
+    type Msg = String;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        ctx.system.event_stream().subscribe::<String>(ctx.myself());
+        self.probe.event("subscribed".into());
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(msg);
+    }
+}
+
+#[test]
+fn channel_event_stream() {
+    let sys = ActorSystem::new().unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|probe| StreamSub { probe }, probe);
+    sys.actor_of(props, "stream-sub").unwrap();
+    listen.recv();
+
+    // only messages of the subscribed type are delivered
+    sys.event_stream().publish(SomeMessage, None);
+    sys.event_stream().publish(42u32, None);
+    sys.event_stream().publish("published".to_string(), None);
+
+    p_assert_eq!(listen, "published".to_string());
+}
+
+#[test]
+fn channel_stream() {
+    let sys = ActorSystem::new().unwrap();
+    let chan: ChannelRef<String> = channel("my-chan", &sys).unwrap();
+
+    let mut stream = chan.stream("my-topic").unwrap();
+    for msg in &["a", "b"] {
+        chan.tell(Publish { msg: msg.to_string(), topic: "my-topic".into() }, None);
+    }
+    chan.tell(Publish { msg: "other".into(), topic: "other".into() }, None);
+    chan.tell(Publish { msg: "c".into(), topic: "my-topic".into() }, None);
+
+    for msg in &["a", "b", "c"] {
+        assert_eq!(block_on(stream.next()), Some(msg.to_string()));
+    }
+    assert_eq!(stream.dropped(), 0);
+}
+
+#[test]
+fn channel_stream_overflow() {
+    let sys = ActorSystem::new().unwrap();
+    let chan: ChannelRef<String> = channel("my-chan", &sys).unwrap();
+
+    let mut stream = chan.stream_with_buffer("my-topic", 2).unwrap();
+    for i in 0..10 {
+        chan.tell(Publish { msg: i.to_string(), topic: "my-topic".into() }, None);
+    }
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    // the oldest messages are buffered and the rest are dropped
+    let dropped = stream.dropped();
+    assert!(dropped > 0);
+
+    for i in 0..10 - dropped {
+        assert_eq!(block_on(stream.next()), Some(i.to_string()));
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::Duration
+};
+
+use riker::actors::*;
+
+// Captures the log lines written by all actor systems in this test
+struct Capture;
+
+static LINES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl log::Log for Capture {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        LINES.lock().unwrap().push(format!("{}", record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+#[derive(Clone, Debug)]
+pub struct Noise;
+
+#[derive(Clone, Debug)]
+pub struct Tick;
+
+struct Silent;
+
+impl Actor for Silent {
+    type Msg = Noise;
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                _msg: Self::Msg,
+                _sender: Sender) {}
+}
+
+fn stopped(sys: &ActorSystem, name: &str) -> BasicActorRef {
+    let actor = sys.actor_of(Props::new(|| Silent), name).unwrap();
+    sys.stop(&actor);
+    actor.into()
+}
+
+#[test]
+fn dead_letter_logger() {
+    // the first logger set is kept, so systems won't replace this one
+    log::set_boxed_logger(Box::new(Capture)).unwrap();
+    log::set_max_level(log::LevelFilter::Info);
+
+    let mut cfg = riker::load_config();
+    cfg.set("dead_letters.max_logged", 2).unwrap();
+    cfg.set("dead_letters.interval_millis", 500).unwrap();
+    cfg.set("dead_letters.suppress_paths", vec!["/user/quiet-*", "/user/[bad"]).unwrap();
+    cfg.set("dead_letters.expected_types", vec!["Tick"]).unwrap();
+
+    // a malformed suppress path is ignored
+    let sys = SystemBuilder::new().name("dead-letters").cfg(cfg).create().unwrap();
+
+    let target = stopped(&sys, "target");
+    let quiet = stopped(&sys, "quiet-1");
+    thread::sleep(Duration::from_millis(500));
+
+    // dead letters to suppressed paths and of expected types aren't logged
+    for _ in 0..3 {
+        let _ = quiet.try_tell(Noise, None);
+        let _ = target.try_tell(Tick, None);
+    }
+
+    // only `max_logged` dead letters are logged each interval
+    for _ in 0..5 {
+        let _ = target.try_tell(Noise, None);
+    }
+    thread::sleep(Duration::from_millis(1000));
+
+    let lines = LINES.lock().unwrap();
+    let dead_letters: Vec<&String> = lines.iter()
+        .filter(|l| l.starts_with("DeadLetter:"))
+        .collect();
+
+    assert!(lines.iter().any(|l| l.contains("[bad")));
+    assert!(!dead_letters.iter().any(|l| l.contains("quiet-1") || l.contains("Tick")));
+    assert_eq!(dead_letters.iter().filter(|l| l.contains("Noise")).count(), 2);
+    assert!(dead_letters.iter().any(|l| l.contains("3 more dead letters were suppressed")));
+}
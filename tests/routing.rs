@@ -0,0 +1,502 @@
+#[macro_use]
+extern crate riker_testkit;
+
+use std::{
+    collections::HashMap,
+    thread,
+    time::Duration
+};
+
+use riker::actors::*;
+use riker::routing::*;
+
+use riker_testkit::probe::{Probe, ProbeReceive};
+use riker_testkit::probe::channel::{probe, ChannelProbe};
+
+#[derive(Clone, Debug)]
+pub struct Job {
+    key: u64,
+    panic: bool,
+    delay: u64,
+    probe: ChannelProbe<(), (u64, String)>,
+}
+
+impl Job {
+    fn new(key: u64, probe: &ChannelProbe<(), (u64, String)>) -> Self {
+        Job {
+            key,
+            panic: false,
+            delay: 0,
+            probe: probe.clone()
+        }
+    }
+}
+
+struct Worker;
+
+impl Worker {
+    fn new() -> Self {
+        Worker
+    }
+}
+
+impl Actor for Worker {
+    type Msg = Job;
+
+    fn recv(&mut self,
+                ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+
+        if msg.panic {
+            panic!("// TEST PANIC // TEST PANIC // TEST PANIC //");
+        }
+
+        thread::sleep(Duration::from_millis(msg.delay));
+
+        msg.probe.event((msg.key, ctx.myself.name().to_string()));
+    }
+}
+
+fn key(msg: &Job) -> u64 {
+    msg.key
+}
+
+#[test]
+fn router_consistent_hashing() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Pool::props(Props::new(Worker::new), 5, ConsistentHashing::new(key));
+    let router = sys.actor_of(props, "router").unwrap();
+
+    let (probe, listen) = probe();
+
+    for _ in 0..4 {
+        for key in 0..25 {
+            router.tell(Job::new(key, &probe), None);
+        }
+    }
+
+    // every message with the same key is handled by the same routee
+    let mut routees = HashMap::new();
+    for _ in 0..100 {
+        let (key, routee) = listen.recv();
+        assert_eq!(routees.entry(key).or_insert(routee.clone()), &routee);
+    }
+}
+
+#[test]
+fn router_consistent_hashing_restart() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Pool::props(Props::new(Worker::new), 5, ConsistentHashing::new(key));
+    let router = sys.actor_of(props, "router").unwrap();
+
+    let (probe, listen) = probe();
+
+    router.tell(Job::new(7, &probe), None);
+    let (_, before) = listen.recv();
+
+    // force the routee handling key 7 to restart
+    router.tell(Job { panic: true, .. Job::new(7, &probe) }, None);
+
+    router.tell(Job::new(7, &probe), None);
+    p_assert_eq!(listen, (7, before));
+}
+
+#[test]
+fn router_smallest_mailbox() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Pool::props(Props::new(Worker::new), 3, SmallestMailbox);
+    let router = sys.actor_of(props, "router").unwrap();
+
+    let (probe, listen) = probe();
+
+    // keep one routee busy
+    router.tell(Job { delay: 1000, .. Job::new(0, &probe) }, None);
+
+    for key in 1..6 {
+        thread::sleep(Duration::from_millis(50));
+        router.tell(Job::new(key, &probe), None);
+    }
+
+    // all other jobs are routed to the idle routees,
+    // completing before the busy routee finishes
+    let mut busy = None;
+    for _ in 0..6 {
+        let (key, routee) = listen.recv();
+        if key == 0 {
+            busy = Some(routee);
+        } else {
+            assert!(busy.is_none());
+        }
+    }
+}
+
+#[test]
+fn router_balancing_pool() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = BalancingPool::props(Props::new(Worker::new), 3);
+    let router = sys.actor_of(props, "router").unwrap();
+
+    let (probe, listen) = probe();
+
+    router.tell(Job { delay: 1000, .. Job::new(0, &probe) }, None);
+
+    for key in 1..11 {
+        router.tell(Job::new(key, &probe), None);
+    }
+
+    // queued jobs are taken by the free routees
+    // while one routee is busy with the slow job
+    for _ in 1..11 {
+        let (key, _) = listen.recv();
+        assert_ne!(key, 0);
+    }
+
+    let (key, _) = listen.recv();
+    assert_eq!(key, 0);
+}
+
+#[test]
+fn router_balancing_pool_bounded() {
+    let mut cfg = riker::load_config();
+    cfg.set("mailbox.capacity", 2).unwrap();
+    let sys = SystemBuilder::new().name("bounded").cfg(cfg).create().unwrap();
+
+    let props = BalancingPool::props(Props::new(Worker::new), 1);
+    sys.actor_of(props, "router").unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let (probe, listen) = probe();
+    let routee = sys.lookup("/user/router/routee-0").unwrap();
+
+    // the routee is busy with the slow job while the shared queue fills up
+    routee.try_tell(Job { delay: 500, .. Job::new(0, &probe) }, None).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let err = (1..4)
+        .map(|key| routee.try_tell(Job::new(key, &probe), None))
+        .find_map(Result::err);
+    assert_eq!(err, Some(TellError::MailboxFull));
+
+    let (key, _) = listen.recv();
+    assert_eq!(key, 0);
+}
+
+fn broadcast_recipients(router: &ActorRef<RouterMsg<Job>>, count: usize) -> Vec<String> {
+    let (probe, listen) = probe();
+    router.tell(RouterMsg::Broadcast(Job::new(0, &probe)), None);
+
+    let mut names: Vec<String> = (0..count).map(|_| listen.recv().1).collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn router_group() {
+    let sys = ActorSystem::new().unwrap();
+
+    let worker_a = sys.actor_of(Props::new(Worker::new), "worker-a").unwrap();
+    sys.actor_of(Props::new(Worker::new), "worker-b").unwrap();
+
+    let paths = vec!["/user/worker-a".into(),
+                    "/user/worker-b".into(),
+                    "/user/worker-c".into()];
+    let props = Group::<Job, _>::props(paths, SmallestMailbox);
+    let router = sys.actor_of(props, "router").unwrap();
+
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(broadcast_recipients(&router, 2), vec!["worker-a", "worker-b"]);
+
+    // actors created after the router are added to the group
+    sys.actor_of(Props::new(Worker::new), "worker-c").unwrap();
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(broadcast_recipients(&router, 3), vec!["worker-a", "worker-b", "worker-c"]);
+
+    // terminated actors are removed from the group
+    sys.stop(&worker_a);
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(broadcast_recipients(&router, 2), vec!["worker-b", "worker-c"]);
+}
+
+#[test]
+fn router_group_pattern() {
+    let sys = ActorSystem::new().unwrap();
+
+    sys.actor_of(Props::new(Worker::new), "shard-1").unwrap();
+
+    let paths = vec!["/user/shard-*".into(), "/user/**/replica".into()];
+    let props = Group::<Job, _>::props(paths, SmallestMailbox);
+    let router = sys.actor_of(props, "router").unwrap();
+
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(broadcast_recipients(&router, 1), vec!["shard-1"]);
+
+    // actors created after the router are matched by path
+    let shard = sys.actor_of(Props::new(Worker::new), "shard-2").unwrap();
+    sys.actor_of(Props::new(Worker::new), "worker").unwrap();
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(broadcast_recipients(&router, 2), vec!["shard-1", "shard-2"]);
+
+    assert!(sys.select("/user/shard-*").unwrap().matches("/user/shard-2"));
+    assert!(sys.select("/user/**/replica").unwrap().matches("/user/a/b/replica"));
+    assert!(!sys.select("/user/*").unwrap().matches("/user/a/b"));
+    assert!(!sys.select("/system/**").unwrap().matches(&shard.path().to_string()));
+}
+
+#[derive(Clone, Debug)]
+pub struct Lookup {
+    slow: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LookupResult {
+    Found(String),
+    TimedOut,
+}
+
+// only routee-1 replies quickly
+struct Replica;
+
+impl Actor for Replica {
+    type Msg = Lookup;
+
+    fn recv(&mut self,
+                ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                sender: Sender) {
+
+        if ctx.myself.name() != "routee-1" {
+            thread::sleep(Duration::from_millis(msg.slow));
+        }
+
+        let result = LookupResult::Found(ctx.myself.name().to_string());
+        let _ = sender.unwrap().try_tell(result, Some(ctx.myself().into()));
+    }
+}
+
+struct Requester {
+    probe: ChannelProbe<(), LookupResult>,
+}
+
+impl Requester {
+    fn props(probe: ChannelProbe<(), LookupResult>) -> BoxActorProd<Requester> {
+        Props::new_args(|probe| Requester { probe }, probe)
+    }
+}
+
+impl Actor for Requester {
+    type Msg = LookupResult;
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(msg);
+    }
+}
+
+#[test]
+fn router_scatter_gather() {
+    let sys = ActorSystem::new().unwrap();
+
+    let logic = ScatterGatherFirstCompleted::new(Duration::from_millis(1000),
+                                                    LookupResult::TimedOut);
+    let router = sys.actor_of(Pool::props(Props::new(|| Replica), 3, logic), "router").unwrap();
+
+    let (probe, listen) = probe();
+    let requester = sys.actor_of(Requester::props(probe), "requester").unwrap();
+
+    router.tell(Lookup { slow: 500 }, Some(requester.clone().into()));
+    p_assert_eq!(listen, LookupResult::Found("routee-1".into()));
+
+    // no replica replies in time
+    let logic = ScatterGatherFirstCompleted::new(Duration::from_millis(100),
+                                                    LookupResult::TimedOut);
+    let props = Pool::props(Props::new(|| Replica), 1, logic);
+    let router = sys.actor_of(props, "router-timeout").unwrap();
+
+    router.tell(Lookup { slow: 500 }, Some(requester.into()));
+    p_assert_eq!(listen, LookupResult::TimedOut);
+}
+
+#[test]
+fn router_tail_chopping() {
+    let sys = ActorSystem::new().unwrap();
+
+    let logic = TailChopping::new(Duration::from_millis(1000),
+                                    Duration::from_millis(50),
+                                    LookupResult::TimedOut);
+    let router = sys.actor_of(Pool::props(Props::new(|| Replica), 3, logic), "router").unwrap();
+
+    let (probe, listen) = probe();
+    let requester = sys.actor_of(Requester::props(probe), "requester").unwrap();
+
+    router.tell(Lookup { slow: 500 }, Some(requester.clone().into()));
+    p_assert_eq!(listen, LookupResult::Found("routee-1".into()));
+
+    let logic = TailChopping::new(Duration::from_millis(100),
+                                    Duration::from_millis(50),
+                                    LookupResult::TimedOut);
+    let props = Pool::props(Props::new(|| Replica), 1, logic);
+    let router = sys.actor_of(props, "router-timeout").unwrap();
+
+    router.tell(Lookup { slow: 500 }, Some(requester.into()));
+    p_assert_eq!(listen, LookupResult::TimedOut);
+}
+
+#[derive(Clone, Debug)]
+pub struct Ping(ChannelProbe<(), String>);
+
+// replies as soon as it receives a message
+struct FastReplica;
+
+impl Actor for FastReplica {
+    type Msg = Ping;
+
+    fn recv(&mut self,
+                ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                sender: Sender) {
+        msg.0.event("ping".into());
+
+        let result = LookupResult::Found(ctx.myself.name().to_string());
+        let _ = sender.unwrap().try_tell(result, Some(ctx.myself().into()));
+    }
+}
+
+struct DeadLetterSub {
+    probe: ChannelProbe<(), String>,
+}
+
+impl Actor for DeadLetterSub {
+    type Msg = DeadLetter;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Box::new(ctx.myself());
+        ctx.system
+            .dead_letters()
+            .tell(Subscribe { actor: sub, topic: "*".into() }, None);
+    }
+
+    fn recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: Self::Msg,
+                _sender: Sender) {
+        self.probe.event(format!("dead letter: {}", msg.recipient.path()));
+    }
+}
+
+#[test]
+fn router_immediate_reply() {
+    let sys = ActorSystem::new().unwrap();
+
+    let (reply_probe, reply_listen) = probe();
+    let requester = sys.actor_of(Requester::props(reply_probe), "requester").unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|probe| DeadLetterSub { probe }, probe.clone());
+    sys.actor_of(props, "dl-sub").unwrap();
+
+    // the timeout is cancelled, not sent to the stopped gatherer
+    let logic = ScatterGatherFirstCompleted::new(Duration::from_millis(500),
+                                                    LookupResult::TimedOut);
+    let props = Pool::props(Props::new(|| FastReplica), 1, logic);
+    let router = sys.actor_of(props, "scatter").unwrap();
+
+    router.tell(Ping(probe.clone()), Some(requester.clone().into()));
+    p_assert_eq!(listen, "ping".to_string());
+    p_assert_eq!(reply_listen, LookupResult::Found("routee-0".into()));
+
+    thread::sleep(Duration::from_millis(700));
+    probe.event("end".into());
+    p_assert_eq!(listen, "end".to_string());
+
+    // no other routee is tried once the first has replied
+    let logic = TailChopping::new(Duration::from_millis(1000),
+                                    Duration::from_millis(500),
+                                    LookupResult::TimedOut);
+    let props = Pool::props(Props::new(|| FastReplica), 3, logic);
+    let router = sys.actor_of(props, "tail-chopping").unwrap();
+
+    router.tell(Ping(probe.clone()), Some(requester.into()));
+    p_assert_eq!(listen, "ping".to_string());
+    match reply_listen.recv() {
+        LookupResult::Found(_) => {}
+        res => panic!("unexpected reply: {:?}", res)
+    }
+
+    thread::sleep(Duration::from_millis(1200));
+    probe.event("end".into());
+    p_assert_eq!(listen, "end".to_string());
+}
+
+struct ResizeListener {
+    probe: ChannelProbe<(), (usize, usize)>,
+}
+
+impl ResizeListener {
+    fn props(probe: ChannelProbe<(), (usize, usize)>) -> BoxActorProd<ResizeListener> {
+        Props::new_args(|probe| ResizeListener { probe }, probe)
+    }
+}
+
+impl Actor for ResizeListener {
+    type Msg = SystemEvent;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let sub = Subscribe {
+            topic: SysTopic::RouterResized.into(),
+            actor: Box::new(ctx.myself())
+        };
+        ctx.system.sys_events().tell(sub, None);
+    }
+
+    fn recv(&mut self, _: &Context<Self::Msg>, _: Self::Msg, _: Sender) {}
+
+    fn sys_recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: SystemMsg,
+                _sender: Sender) {
+
+        if let SystemMsg::Event(SystemEvent::RouterResized(resized)) = msg {
+            self.probe.event((resized.previous, resized.size));
+        }
+    }
+}
+
+#[test]
+fn router_pool_resizer() {
+    let sys = ActorSystem::new().unwrap();
+
+    let (job_probe, _job_listen) = probe();
+    let (probe, listen) = probe();
+    sys.actor_of(ResizeListener::props(probe), "listener").unwrap();
+    thread::sleep(Duration::from_millis(50));
+
+    let resizer = Resizer::new(1, 3)
+                    .rampup_rate(1.0)
+                    .interval(Duration::from_millis(100));
+    let props = Pool::props_with_resizer(Props::new(Worker::new), SmallestMailbox, resizer);
+    let router = sys.actor_of(props, "router").unwrap();
+
+    for key in 0..20 {
+        router.tell(Job { delay: 50, .. Job::new(key, &job_probe) }, None);
+    }
+
+    // the pool grows while the routee is under pressure
+    let (previous, size) = listen.recv();
+    assert_eq!(previous, 1);
+    assert!(size > 1);
+
+    // and shrinks back to the lower bound once the load has been processed
+    let mut size = size;
+    while size > 1 {
+        let (_, resized) = listen.recv();
+        assert!(resized <= 3);
+        size = resized;
+    }
+}
@@ -129,6 +129,56 @@ fn select_all_children_of_child() {
     p_assert_eq!(listen, ());
 }
 
+#[test]
+fn select_glob() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Props::new(SelectTest::new);
+    sys.actor_of(props, "select-actor").unwrap();
+
+    // delay to allow 'select-actor' pre_start to create 'child_a' and 'child_b'
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let (probe, listen) = probe();
+
+    // 'child_a' and 'child_b'
+    let sel = sys.select("select-*/child_?").unwrap();
+    sel.try_tell(TestProbe(probe.clone()), None);
+
+    p_assert_eq!(listen, ());
+    p_assert_eq!(listen, ());
+
+    // 'child_b' only
+    let sel = sys.select("select-actor/child_[!a]").unwrap();
+    assert_eq!(sel.resolve().len(), 1);
+    sel.try_tell(TestProbe(probe), None);
+
+    p_assert_eq!(listen, ());
+}
+
+#[test]
+fn select_recursive() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Props::new(SelectTest::new);
+    sys.actor_of(props, "select-actor").unwrap();
+
+    // delay to allow 'select-actor' pre_start to create 'child_a' and 'child_b'
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let sel = sys.select("/user/**/child_a").unwrap();
+    let actors = sel.resolve();
+    assert_eq!(actors.len(), 1);
+    assert_eq!(actors[0].path(), "/user/select-actor/child_a");
+
+    // '**' matches zero or more levels
+    let sel = sys.select("**/child_*").unwrap();
+    assert_eq!(sel.resolve().len(), 2);
+
+    let sel = sys.select("**/select-actor").unwrap();
+    assert_eq!(sel.resolve().len(), 1);
+}
+
 #[derive(Clone)]
 struct SelectTest2;
 
@@ -209,67 +259,189 @@ fn select_paths() {
     assert!(sys.select("../foo/").is_ok());
     assert!(sys.select("/foo/*").is_ok());
     assert!(sys.select("*").is_ok());
+    assert!(sys.select("/foo/**").is_ok());
+    assert!(sys.select("foo/worker-*").is_ok());
+    assert!(sys.select("**/conn-[0-9]").is_ok());
+    assert!(sys.select("foo/conn-[!0]?").is_ok());
 
     assert!(sys.select("foo/`").is_err());
     assert!(sys.select("foo/@").is_err());
     assert!(sys.select("!").is_err());
     assert!(sys.select("foo/$").is_err());
     assert!(sys.select("&").is_err());
+    assert!(sys.select("foo/[a").is_err());
 }
 
-// // *** Dead letters test ***
-// struct DeadLettersActor {
-//     probe: Option<TestProbe>,
-// }
-
-// impl DeadLettersActor {
-//     fn new() -> BoxActor<TestMsg> {
-//         let actor = DeadLettersActor {
-//             probe: None
-//         };
-
-//         Box::new(actor)
-//     }
-// }
-
-// impl Actor for DeadLettersActor {
-//     type Msg = TestMsg;
-
-//     fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
-//         // subscribe to dead_letters
-//         let msg = ChannelMsg::Subscribe(All.into(), ctx.myself());
-//         ctx.system.dead_letters().tell(msg, None);
-//     }
-
-//     fn receive(&mut self, _: &Context<Self::Msg>, msg: Self::Msg, _: Option<ActorRef<Self::Msg>>) {
-//         msg.0.event(()); // notify listen then probe has been received.
-//         self.probe = Some(msg.0);
-//     }
-
-//     fn other_receive(&mut self, _: &Context<Self::Msg>, msg: ActorMsg<Self::Msg>, _: Option<ActorRef<Self::Msg>>) {
-//         if let ActorMsg::DeadLetter(dl) = msg {
-//             println!("DeadLetter: {} => {} ({:?})", dl.sender, dl.recipient, dl.msg);
-//             self.probe.event(());
-//         }
-//     }
-// }
-
-// #[test]
-// fn select_no_actors() {
-//     let sys = ActorSystem::new().unwrap();
-
-//     let props = Props::new(DeadLettersActor::new);
-//     let act = sys.actor_of(props, "dl-subscriber").unwrap();
-
-//     let (probe, listen) = probe();
-//     act.tell(TestMsg(probe.clone()), None);
-    
-//     // wait for the probe to arrive at the dl sub before doing select
-//     listen.recv();
+#[test]
+fn select_absolute() {
+    let sys = ActorSystem::new().unwrap();
 
-//     let sel = sys.select("nothing-here").unwrap();
-    
-//     sel.tell(TestMsg(probe), None);
+    // absolute paths can select actors under any guardian
+    let sel = sys.select("/system/dead_letters").unwrap();
+    let actors = sel.resolve();
+    assert_eq!(actors.len(), 1);
+    assert_eq!(actors[0].path(), "/system/dead_letters");
+
+    let sel = sys.select("/*").unwrap();
+    assert_eq!(sel.resolve().len(), 3);
+}
+
+#[test]
+fn lookup_path() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Props::new(SelectTest::new);
+    let actor = sys.actor_of(props, "select-actor").unwrap();
+
+    // delay to allow 'select-actor' pre_start to create 'child_a' and 'child_b'
+    std::thread::sleep(std::time::Duration::from_millis(500));
 
-//     p_assert_eq!(listen, ());
-// }
+    let child = sys.lookup("/user/select-actor/child_a").unwrap();
+    assert_eq!(child.path(), "/user/select-actor/child_a");
+
+    assert!(sys.lookup("/user/select-actor").is_some());
+    assert!(sys.lookup("/user").is_some());
+    assert!(sys.lookup("/system/dead_letters").is_some());
+    assert!(sys.lookup("/user/nothing-here").is_none());
+
+    // terminated actors are removed
+    sys.stop(&actor);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    assert!(sys.lookup("/user/select-actor").is_none());
+    assert!(sys.lookup("/user/select-actor/child_a").is_none());
+}
+
+#[test]
+fn parse_address() {
+    let addr: ActorAddress = "riker://my-app@localhost:8000/user/worker#101".parse().unwrap();
+    assert_eq!(addr.system, "my-app");
+    assert_eq!(addr.host, "localhost");
+    assert_eq!(addr.port, Some(8000));
+    assert_eq!(addr.path, "/user/worker");
+    assert_eq!(addr.uid, Some(101));
+    assert_eq!(addr.to_string(), "riker://my-app@localhost:8000/user/worker#101");
+
+    let addr: ActorAddress = "riker://my-app@localhost/user/worker".parse().unwrap();
+    assert_eq!(addr.port, None);
+    assert_eq!(addr.uid, None);
+    assert_eq!(addr.to_string(), "riker://my-app@localhost/user/worker");
+
+    assert!("http://my-app@localhost/user".parse::<ActorAddress>().is_err());
+    assert!("riker://localhost/user".parse::<ActorAddress>().is_err());
+    assert!("riker://my-app@localhost".parse::<ActorAddress>().is_err());
+    assert!("riker://my-app@localhost:port/user".parse::<ActorAddress>().is_err());
+    assert!("riker://my-app@localhost/user#abc".parse::<ActorAddress>().is_err());
+    assert!("riker://my-app@/user".parse::<ActorAddress>().is_err());
+}
+
+#[test]
+fn resolve_address() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Props::new(SelectTest::new);
+    let actor = sys.actor_of(props, "select-actor").unwrap();
+
+    let addr = actor.uri().address();
+    assert_eq!(addr.system, sys.name());
+    assert_eq!(addr.path, "/user/select-actor");
+
+    let resolved = sys.resolve_address(&addr.to_string()).unwrap();
+    assert_eq!(resolved.path(), actor.path());
+
+    // without a uid any incarnation at the path matches
+    let no_uid = format!("riker://{}@{}/user/select-actor", sys.name(), sys.host());
+    assert!(sys.resolve_address(&no_uid).is_some());
+
+    // a different incarnation does not match
+    let other_uid = format!("{}0", addr);
+    assert!(sys.resolve_address(&other_uid).is_none());
+
+    // addresses of other systems are not local
+    let other_sys = format!("riker://other@{}/user/select-actor", sys.host());
+    assert!(sys.resolve_address(&other_sys).is_none());
+
+    let port = format!("riker://{}@{}:9999/user/select-actor", sys.name(), sys.host());
+    assert!(sys.resolve_address(&port).is_none());
+
+    assert!(sys.resolve_address("not an address").is_none());
+}
+
+// *** Dead letters test ***
+struct DeadLettersActor {
+    probe: Option<TestProbe>,
+}
+
+impl DeadLettersActor {
+    fn new() -> Self {
+        DeadLettersActor {
+            probe: None
+        }
+    }
+}
+
+impl Actor for DeadLettersActor {
+    type Msg = DeadLettersMsg;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        // subscribe to dead_letters
+        let sub = Box::new(ctx.myself());
+        ctx.system
+            .dead_letters()
+            .tell(Subscribe { actor: sub, topic: "*".into() }, None);
+    }
+
+    fn recv(&mut self,
+            _ctx: &Context<Self::Msg>,
+            msg: Self::Msg,
+            _sender: Sender) {
+
+        match msg {
+            DeadLettersMsg::TestProbe(probe) => {
+                probe.0.event(()); // notify listen then probe has been received.
+                self.probe = Some(probe);
+            }
+            DeadLettersMsg::DeadLetter(_) => {
+                self.probe.as_ref().unwrap().0.event(());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum DeadLettersMsg {
+    TestProbe(TestProbe),
+    DeadLetter(DeadLetter),
+}
+
+impl From<TestProbe> for DeadLettersMsg {
+    fn from(msg: TestProbe) -> Self {
+        DeadLettersMsg::TestProbe(msg)
+    }
+}
+
+impl From<DeadLetter> for DeadLettersMsg {
+    fn from(msg: DeadLetter) -> Self {
+        DeadLettersMsg::DeadLetter(msg)
+    }
+}
+
+#[test]
+fn select_no_actors() {
+    let sys = ActorSystem::new().unwrap();
+
+    let props = Props::new(DeadLettersActor::new);
+    let act = sys.actor_of(props, "dl-subscriber").unwrap();
+
+    let (probe, listen) = probe();
+    act.tell(TestProbe(probe.clone()), None);
+
+    // wait for the probe to arrive at the dl sub before doing select
+    listen.recv();
+
+    let sel = sys.select("nothing-here").unwrap();
+
+    sel.try_tell(TestProbe(probe), None);
+
+    p_assert_eq!(listen, ());
+}
@@ -314,3 +314,82 @@ fn supervision_escalate_failed_actor() {
     p_assert_eq!(listen, ());
     sys.print_tree();
 }
+
+// Sends a description of failure events to a probe
+struct FailureEvents {
+    probe: ChannelProbe<(), String>,
+}
+
+impl Actor for FailureEvents {
+    type Msg = SystemEvent;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        let topics = [SysTopic::ActorFailed, SysTopic::SupervisorDecision, SysTopic::ActorFailedToStart];
+        for topic in topics {
+            let sub = Box::new(ctx.myself());
+            ctx.system
+                .sys_events()
+                .tell(Subscribe { actor: sub, topic: topic.into() }, None);
+        }
+        self.probe.event("subscribed".into());
+    }
+
+    fn sys_recv(&mut self,
+                _ctx: &Context<Self::Msg>,
+                msg: SystemMsg,
+                _sender: Sender) {
+        let desc = match msg {
+            SystemMsg::Event(SystemEvent::ActorFailed(evt)) => {
+                format!("failed {} {}", evt.actor.path(), evt.reason)
+            }
+            SystemMsg::Event(SystemEvent::SupervisorDecision(evt)) => {
+                format!("decision {} {} {:?}", evt.supervisor.path(), evt.actor.path(), evt.strategy)
+            }
+            SystemMsg::Event(SystemEvent::ActorFailedToStart(evt)) => {
+                format!("start_failed {}", evt.uri.path)
+            }
+            _ => return
+        };
+        self.probe.event(desc);
+    }
+
+    fn recv(&mut self, _: &Context<Self::Msg>, _: Self::Msg, _: Sender) {}
+}
+
+struct StartPanic;
+
+impl StartPanic {
+    fn new() -> Self {
+        panic!("// TEST PANIC // START //");
+    }
+}
+
+impl Actor for StartPanic {
+    type Msg = ();
+
+    fn recv(&mut self, _: &Context<Self::Msg>, _: Self::Msg, _: Sender) {}
+}
+
+#[test]
+fn supervision_failure_events() {
+    let sys = ActorSystem::new().unwrap();
+
+    let (probe, listen) = probe();
+    let props = Props::new_args(|probe| FailureEvents { probe }, probe);
+    sys.actor_of(props, "failure-events").unwrap();
+    listen.recv();
+
+    let sup = sys.actor_of(Props::new(RestartSup::new), "supervisor").unwrap();
+    sup.tell(Panic, None);
+
+    // the failure and the decision are published from different actors
+    let mut events = vec![listen.recv(), listen.recv()];
+    events.sort();
+    assert_eq!(events, vec![
+        "decision /user/supervisor /user/supervisor/actor-to-fail Restart".to_string(),
+        "failed /user/supervisor/actor-to-fail // TEST PANIC // TEST PANIC // TEST PANIC //".to_string()
+    ]);
+
+    assert!(sys.actor_of(Props::new(StartPanic::new), "start-panic").is_err());
+    p_assert_eq!(listen, "start_failed /user/start-panic".to_string());
+}